@@ -0,0 +1,52 @@
+//! Compares printing a field with an expensive formatter with and without `cache_format`.
+//!
+//! Run using `cargo bench --bench cache_format`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use derive_debug::Dbg;
+
+fn expensive(data: &[u8]) -> String {
+    data.iter().map(|b| format!("{:08b}", b)).collect()
+}
+
+#[derive(Dbg)]
+struct Uncached {
+    #[dbg(formatter = "expensive")]
+    data: Vec<u8>,
+}
+
+#[derive(Dbg)]
+struct Cached {
+    #[dbg(cache_format, formatter = "expensive")]
+    data: Vec<u8>,
+}
+
+fn measure(name: &str, iterations: u32, value: &dyn std::fmt::Debug) -> Duration {
+    let start = Instant::now();
+    for _ in 0..iterations {
+        black_box(format!("{:?}", black_box(value)));
+    }
+    let elapsed = start.elapsed();
+    println!(
+        "{:<10} {:>10.2?} total, {:>10.2?} per iteration",
+        name,
+        elapsed,
+        elapsed / iterations
+    );
+    elapsed
+}
+
+fn main() {
+    let data = (0..=255).cycle().take(4096).collect::<Vec<u8>>();
+    let iterations = 1000;
+
+    let uncached = measure("uncached", iterations, &Uncached { data: data.clone() });
+    let cached = measure("cached", iterations, &Cached { data });
+
+    println!(
+        "speedup: {:.1}x",
+        uncached.as_secs_f64() / cached.as_secs_f64()
+    );
+}
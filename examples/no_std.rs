@@ -0,0 +1,50 @@
+//! Checks that the derive compiles in a `#![no_std]` crate, for all options that only need `core`.
+
+#![no_std]
+#![allow(dead_code)]
+
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+#[dbg(crate_path = "core", show_size, show_types)]
+pub struct Fields<'a> {
+    a: u32,
+    #[dbg(skip_if_none)]
+    b: Option<u8>,
+    #[dbg(byte_len)]
+    c: &'a [u8],
+    #[dbg(rle)]
+    d: [u8; 4],
+    #[dbg(collection)]
+    e: &'a [u16],
+    #[dbg(bytes)]
+    f: [u8; 2],
+    #[dbg(bytes = "pretty")]
+    g: &'a [u8],
+    #[dbg(redact_partial = 2)]
+    h: &'a str,
+    #[dbg(each_formatter = "double")]
+    i: [u32; 2],
+}
+
+#[derive(Dbg)]
+#[dbg(crate_path = "core", max_elements = 2, best_effort)]
+pub enum Variants {
+    Tuple(#[dbg(rle)] [u8; 3], #[dbg(skip_if_none)] Option<u8>),
+    Struct {
+        #[dbg(collection)]
+        values: [u8; 4],
+    },
+}
+
+const PREFIX: &str = "no_std";
+
+#[derive(Dbg)]
+#[dbg(crate_path = "core", name_concat = "PREFIX, \"::\", \"Named\"")]
+pub struct Named {
+    a: u32,
+}
+
+fn double(value: &u32) -> u32 {
+    value * 2
+}
@@ -0,0 +1,156 @@
+//! Case conversion used by the `rename_all` option.
+
+/// A casing style field and variant names can be converted to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum RenameRule {
+    /// `snake_case`
+    Snake,
+    /// `camelCase`
+    Camel,
+    /// `PascalCase`
+    Pascal,
+    /// `SCREAMING_SNAKE_CASE`
+    ScreamingSnake,
+    /// `kebab-case`
+    Kebab,
+}
+
+impl RenameRule {
+    /// Parses the name of a casing style as written in the attribute.
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "snake_case" => Some(Self::Snake),
+            "camelCase" => Some(Self::Camel),
+            "PascalCase" => Some(Self::Pascal),
+            "SCREAMING_SNAKE_CASE" => Some(Self::ScreamingSnake),
+            "kebab-case" => Some(Self::Kebab),
+            _ => None,
+        }
+    }
+
+    /// Converts `name`, which may be written in any of the supported styles, to this style.
+    pub(crate) fn apply(self, name: &str) -> String {
+        let words = split_words(name);
+
+        match self {
+            Self::Snake => join_lowercase(&words, "_"),
+            Self::Kebab => join_lowercase(&words, "-"),
+            Self::ScreamingSnake => words
+                .iter()
+                .map(|word| word.to_uppercase())
+                .collect::<Vec<_>>()
+                .join("_"),
+            Self::Pascal => words.iter().map(|word| capitalize(word)).collect(),
+            Self::Camel => words
+                .iter()
+                .enumerate()
+                .map(|(i, word)| {
+                    if i == 0 {
+                        word.to_lowercase()
+                    } else {
+                        capitalize(word)
+                    }
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Splits `name` at underscores, dashes and case changes, e.g. `HTTPServer_v2` into `HTTP`, `Server` and `v2`.
+fn split_words(name: &str) -> Vec<&str> {
+    let mut words = Vec::new();
+
+    for part in name.split(['_', '-']) {
+        let chars = part.char_indices().collect::<Vec<_>>();
+        let mut start = 0;
+
+        for i in 1..chars.len() {
+            let (idx, c) = chars[i];
+            let prev = chars[i - 1].1;
+            let next_is_lower = chars.get(i + 1).is_some_and(|(_, c)| c.is_lowercase());
+
+            // a new word starts at `aB`, and at the last capital of an acronym followed by lowercase letters (`ABc`)
+            if c.is_uppercase()
+                && (prev.is_lowercase()
+                    || prev.is_ascii_digit()
+                    || (prev.is_uppercase() && next_is_lower))
+            {
+                words.push(&part[start..idx]);
+                start = idx;
+            }
+        }
+
+        if start < part.len() {
+            words.push(&part[start..]);
+        }
+    }
+
+    words
+}
+
+fn join_lowercase(words: &[&str], separator: &str) -> String {
+    words
+        .iter()
+        .map(|word| word.to_lowercase())
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first
+            .to_uppercase()
+            .chain(chars.flat_map(char::to_lowercase))
+            .collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_snake_case() {
+        assert_eq!(RenameRule::Snake.apply("field_name"), "field_name");
+        assert_eq!(RenameRule::Snake.apply("VariantName"), "variant_name");
+        assert_eq!(RenameRule::Snake.apply("HTTPServer"), "http_server");
+    }
+
+    #[test]
+    fn test_camel_case() {
+        assert_eq!(RenameRule::Camel.apply("field_name"), "fieldName");
+        assert_eq!(RenameRule::Camel.apply("VariantName"), "variantName");
+        assert_eq!(RenameRule::Camel.apply("a"), "a");
+    }
+
+    #[test]
+    fn test_pascal_case() {
+        assert_eq!(RenameRule::Pascal.apply("field_name"), "FieldName");
+        assert_eq!(RenameRule::Pascal.apply("VariantName"), "VariantName");
+        assert_eq!(RenameRule::Pascal.apply("ip_v4_addr"), "IpV4Addr");
+    }
+
+    #[test]
+    fn test_screaming_snake_case() {
+        assert_eq!(RenameRule::ScreamingSnake.apply("field_name"), "FIELD_NAME");
+        assert_eq!(
+            RenameRule::ScreamingSnake.apply("VariantName"),
+            "VARIANT_NAME"
+        );
+    }
+
+    #[test]
+    fn test_kebab_case() {
+        assert_eq!(RenameRule::Kebab.apply("field_name"), "field-name");
+        assert_eq!(RenameRule::Kebab.apply("VariantName"), "variant-name");
+        assert_eq!(RenameRule::Kebab.apply("Version2Name"), "version2-name");
+    }
+
+    #[test]
+    fn test_from_name() {
+        assert_eq!(RenameRule::from_name("kebab-case"), Some(RenameRule::Kebab));
+        assert_eq!(RenameRule::from_name("lowercase"), None);
+    }
+}
@@ -0,0 +1,74 @@
+//! Parsing of the arguments following the format string of the `fmt` option.
+//!
+//! The arguments are arbitrary expressions, which are not supported by [`syn::Meta`]:
+//! `#[dbg(fmt = "{}/{}", self.width, height)]`
+
+use proc_macro2::{Delimiter, Ident, TokenStream, TokenTree};
+use quote::quote;
+use syn::{Attribute, Meta, NestedMeta};
+
+/// Parses `attrib` as [`Meta`], splitting off the arguments following `fmt = "..."`.
+///
+/// Options of the form `name = value` or `name(...)` may still follow the arguments.
+/// A bare identifier is only treated as an option if `is_flag` returns `true` for it,
+/// otherwise it is an argument referring to a field.
+pub(crate) fn split_fmt_args(
+    attrib: &Attribute,
+    is_flag: impl Fn(&Ident) -> bool,
+) -> syn::Result<(Meta, Vec<TokenStream>)> {
+    let stream = match attrib.tokens.clone().into_iter().next() {
+        Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Parenthesis => {
+            group.stream()
+        }
+        _ => return Ok((attrib.parse_meta()?, Vec::new())),
+    };
+
+    let mut segments = vec![TokenStream::new()];
+    for token in stream {
+        match &token {
+            TokenTree::Punct(punct) if punct.as_char() == ',' => segments.push(TokenStream::new()),
+            _ => segments.last_mut().unwrap().extend([token]),
+        }
+    }
+
+    let fmt = segments.iter().position(|segment| {
+        let tokens = segment.clone().into_iter().collect::<Vec<_>>();
+        matches!(
+            tokens.as_slice(),
+            [TokenTree::Ident(name), TokenTree::Punct(eq), TokenTree::Literal(_)]
+                if name == "fmt" && eq.as_char() == '='
+        )
+    });
+    let Some(fmt) = fmt else {
+        return Ok((attrib.parse_meta()?, Vec::new()));
+    };
+
+    let (args, options): (Vec<_>, Vec<_>) = segments
+        .split_off(fmt + 1)
+        .into_iter()
+        .filter(|segment| !segment.is_empty())
+        .partition(|segment| is_arg(segment, &is_flag));
+    segments.extend(options);
+
+    let path = &attrib.path;
+    let meta = syn::parse2(quote! { #path(#(#segments),*) })?;
+
+    Ok((meta, args))
+}
+
+fn is_arg(segment: &TokenStream, is_flag: impl Fn(&Ident) -> bool) -> bool {
+    match syn::parse2::<NestedMeta>(segment.clone()) {
+        Ok(NestedMeta::Meta(Meta::Path(path))) => !path.get_ident().is_some_and(is_flag),
+        Ok(NestedMeta::Meta(_)) => false,
+        _ => true,
+    }
+}
+
+/// Returns the name of the field an argument refers to, if it consists of a bare identifier.
+pub(crate) fn field_name(arg: &TokenStream) -> Option<Ident> {
+    let mut tokens = arg.clone().into_iter();
+    match (tokens.next(), tokens.next()) {
+        (Some(TokenTree::Ident(name)), None) if name != "self" => Some(name),
+        _ => None,
+    }
+}
@@ -0,0 +1,96 @@
+//! Parsing of the `group` option, which nests fields into named groups.
+//!
+//! The option uses its own syntax, as lists are not supported by [`syn::Meta`]:
+//! `#[dbg(group("outer", [group("inner", ["a"]), "b"]))]`
+
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{bracketed, parenthesized, Attribute, Ident, LitStr, Token};
+
+/// A named group of fields, printed like a nested struct.
+pub(crate) struct FieldGroup {
+    pub(crate) name: LitStr,
+    pub(crate) items: Vec<GroupItem>,
+}
+
+/// A member of a [`FieldGroup`].
+pub(crate) enum GroupItem {
+    Field(LitStr),
+    Group(FieldGroup),
+}
+
+impl FieldGroup {
+    /// Calls `f` for every field contained in this group or one of its nested groups.
+    pub(crate) fn for_each_field<'a>(&'a self, f: &mut impl FnMut(&'a LitStr)) {
+        for item in &self.items {
+            match item {
+                GroupItem::Field(name) => f(name),
+                GroupItem::Group(group) => group.for_each_field(f),
+            }
+        }
+    }
+}
+
+impl Parse for FieldGroup {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let keyword: Ident = input.parse()?;
+        if keyword != "group" {
+            return Err(syn::Error::new_spanned(keyword, "expected `group`"));
+        }
+
+        let args;
+        parenthesized!(args in input);
+        let name = args.parse()?;
+        args.parse::<Token![,]>()?;
+
+        let items;
+        bracketed!(items in args);
+        let items = Punctuated::<GroupItem, Token![,]>::parse_terminated(&items)?
+            .into_iter()
+            .collect();
+
+        if !args.is_empty() {
+            return Err(args.error("unexpected token"));
+        }
+
+        Ok(Self { name, items })
+    }
+}
+
+impl Parse for GroupItem {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(LitStr) {
+            Ok(Self::Field(input.parse()?))
+        } else {
+            Ok(Self::Group(input.parse()?))
+        }
+    }
+}
+
+/// Returns whether `attrib` is a `#[dbg(group(...))]` attribute.
+pub(crate) fn is_group_attribute(attrib: &Attribute) -> bool {
+    attrib.path.is_ident("dbg")
+        && attrib
+            .parse_args_with(|input: ParseStream| {
+                let is_group = input.fork().parse::<Ident>().is_ok_and(|i| i == "group");
+                input.parse::<proc_macro2::TokenStream>()?;
+                Ok(is_group)
+            })
+            .unwrap_or(false)
+}
+
+/// Parses all groups specified in `attributes`.
+pub(crate) fn find_groups(attributes: &[Attribute]) -> syn::Result<Vec<FieldGroup>> {
+    let mut res = Vec::new();
+
+    for attrib in attributes
+        .iter()
+        .filter(|attrib| is_group_attribute(attrib))
+    {
+        let groups =
+            attrib.parse_args_with(Punctuated::<FieldGroup, Token![,]>::parse_terminated)?;
+        res.extend(groups);
+    }
+
+    Ok(res)
+}
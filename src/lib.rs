@@ -1,12 +1,23 @@
 #![doc = include_str!("../README.md")]
 
-use proc_macro2::TokenStream;
-use quote::{format_ident, quote, ToTokens};
+use proc_macro2::{Group, Span, TokenStream, TokenTree};
+use quote::{format_ident, quote, quote_spanned, ToTokens};
 use syn::{
-    parse_macro_input, Attribute, DataEnum, DataStruct, DeriveInput, Fields,
-    FieldsNamed, FieldsUnnamed, Ident, Lit, LitStr, Meta, MetaNameValue, NestedMeta, Path, Variant,
+    ext::IdentExt, parse_macro_input, punctuated::Punctuated, spanned::Spanned, Attribute,
+    DataEnum, DataStruct, DataUnion, DeriveInput, Expr, Field, Fields, FieldsNamed, FieldsUnnamed,
+    Ident, Lit, LitInt, LitStr, Member, Meta, MetaNameValue, NestedMeta, Path, Token, Type,
+    Variant, WherePredicate,
 };
 
+mod case;
+mod fmt_args;
+mod group;
+mod name_concat;
+
+use case::RenameRule;
+use group::{FieldGroup, GroupItem};
+use name_concat::{NameConcat, NamePart};
+
 /// Derive macro generating an implementation of [`Debug`](std::fmt::Debug)
 /// with more customization options that the normal [`Debug`] derive macro.
 ///
@@ -19,32 +30,189 @@ pub fn derive_debug(target: proc_macro::TokenStream) -> proc_macro::TokenStream
 
 fn derive_debug_impl(item: DeriveInput) -> TokenStream {
     let name = &item.ident;
-    let (impl_generics, type_generics, where_clause) = &item.generics.split_for_impl();
 
     let options = match parse_options(&item.attrs, OptionsTarget::DeriveItem) {
         Ok(options) => options,
         Err(e) => return e.to_compile_error(),
     };
 
-    let display_name = if let Some(alias) = options.alias {
-        alias
-    } else {
-        name.to_string()
+    let display_name = match &options.name_concat {
+        Some(NameConcat { lit, .. }) if matches!(item.data, syn::Data::Enum(_)) => {
+            return syn::Error::new_spanned(lit, "name_concat is not supported for enums")
+                .to_compile_error();
+        }
+        Some(NameConcat { parts, .. }) => derive_concat_name(parts, &options),
+        None => {
+            let display_name = if let Some(alias) = &options.alias {
+                alias.clone()
+            } else {
+                name.unraw().to_string()
+            };
+
+            let display_name = format!(
+                "{}{}{}",
+                options.alias_prefix.as_deref().unwrap_or_default(),
+                display_name,
+                options.alias_suffix.as_deref().unwrap_or_default()
+            );
+
+            if options.crate_prefixed {
+                quote! { concat!(env!("CARGO_PKG_NAME"), "::", #display_name) }
+            } else {
+                quote! { #display_name }
+            }
+        }
     };
 
+    // the name of an enum is only printed as part of its variant names, which are translated as a whole
+    let display_name = match item.data {
+        syn::Data::Enum(_) => display_name,
+        _ => derive_translated_name(display_name, &options),
+    };
+
+    if options.variant_style.is_some() && !matches!(item.data, syn::Data::Enum(_)) {
+        return syn::Error::new_spanned(name, "variant_style is only supported on enums")
+            .to_compile_error();
+    }
+
+    if let (Some(order), syn::Data::Enum(_)) = (&options.order, &item.data) {
+        return syn::Error::new_spanned(order, "order is only supported on structs")
+            .to_compile_error();
+    }
+
+    if let (Some(field), syn::Data::Struct(_) | syn::Data::Enum(_)) =
+        (&options.union_field, &item.data)
+    {
+        return syn::Error::new_spanned(field, "field is only supported on unions")
+            .to_compile_error();
+    }
+
+    if let (Some(version), syn::Data::Enum(_) | syn::Data::Union(_)) =
+        (&options.version, &item.data)
+    {
+        return syn::Error::new_spanned(version, "version is only supported on structs")
+            .to_compile_error();
+    }
+
+    if let (Some(size), syn::Data::Enum(_) | syn::Data::Union(_)) = (&options.show_size, &item.data)
+    {
+        return syn::Error::new_spanned(size, "show_size is only supported on structs")
+            .to_compile_error();
+    }
+
+    if options.transparent && !matches!(item.data, syn::Data::Struct(_)) {
+        return syn::Error::new_spanned(name, "transparent is only supported on structs")
+            .to_compile_error();
+    }
+
+    if let (Some(group), syn::Data::Enum(_) | syn::Data::Union(_)) =
+        (options.groups.first(), &item.data)
+    {
+        return syn::Error::new_spanned(&group.name, "group is only supported on structs")
+            .to_compile_error();
+    }
+
+    if options.discriminant && !matches!(item.data, syn::Data::Enum(_)) {
+        return syn::Error::new_spanned(name, "discriminant is only supported on enums")
+            .to_compile_error();
+    }
+
+    if let Some(param) = options.skip_bound.iter().find(|param| {
+        !item
+            .generics
+            .type_params()
+            .any(|type_param| type_param.ident == **param)
+    }) {
+        return syn::Error::new_spanned(param, format!("unknown type parameter `{}`", param))
+            .to_compile_error();
+    }
+
+    if options.names_from.is_some() && !matches!(item.data, syn::Data::Enum(_)) {
+        return syn::Error::new_spanned(name, "names_from is only supported on enums")
+            .to_compile_error();
+    }
+
     let res = match &item.data {
-        syn::Data::Struct(data) => derive_struct(&display_name, data),
-        syn::Data::Enum(data) => derive_enum(data),
-        syn::Data::Union(data) => Err(syn::Error::new_spanned(
-            data.union_token,
-            "#[derive(Dbg)] not supported on unions",
-        )),
+        syn::Data::Struct(data) => derive_struct(name, &display_name, &options, data),
+        syn::Data::Enum(data) => derive_enum(&display_name, &options, data),
+        syn::Data::Union(data) => derive_union(&display_name, &options, data),
+    };
+
+    // the name only output is generated separately, so that it does not depend on any field options
+    let res = match res {
+        Ok(res) if options.minimal_in_release => {
+            derive_name_only(&display_name, &options, &item.data).map(|name_only| {
+                quote! {
+                    if cfg!(debug_assertions) {
+                        #res
+                    } else {
+                        #name_only
+                    }
+                }
+            })
+        }
+        res => res,
+    };
+
+    let res = match res {
+        Ok(res) if options.fit_width => Ok(derive_fit_width(res)),
+        res => res,
+    };
+
+    let adapter = debug_fn_adapter();
+
+    let cycle_check = if options.detect_cycles {
+        derive_cycle_check()
+    } else {
+        quote! {}
+    };
+
+    let color = if options.color {
+        derive_color_check()
+    } else {
+        quote! {}
+    };
+
+    let mut generics = item.generics.clone();
+    if !options.bounds.is_empty() {
+        generics
+            .make_where_clause()
+            .predicates
+            .extend(options.bounds.iter().cloned());
+    }
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+
+    // everything else refers to `fmt` through the alias declared at the start of the body
+    let krate = &options.crate_path;
+
+    let inline = if options.inline {
+        quote! { #[inline] }
+    } else {
+        quote! {}
+    };
+
+    #[cfg(feature = "testing")]
+    let field_count = match derive_field_count(&item, &generics, &options) {
+        Ok(field_count) => field_count,
+        Err(e) => return e.to_compile_error(),
     };
+    #[cfg(not(feature = "testing"))]
+    let field_count = quote! {};
 
+    // the impl is not marked `#[automatically_derived]`, as the dead code analysis ignores such `Debug` impls,
+    // which would report every field and function that is only used for printing as unused
     match res {
         Ok(res) => quote! {
-            impl #impl_generics ::std::fmt::Debug for #name #type_generics #where_clause {
-                fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+            #field_count
+
+            #[allow(unused_qualifications, clippy::all)]
+            impl #impl_generics ::#krate::fmt::Debug for #name #type_generics #where_clause {
+                #inline
+                fn fmt(&self, f: &mut ::#krate::fmt::Formatter<'_>) -> ::#krate::fmt::Result {
+                    use ::#krate::fmt as __dbg_fmt;
+                    #adapter
+                    #cycle_check
+                    #color
                     #res
                 }
             }
@@ -53,336 +221,3268 @@ fn derive_debug_impl(item: DeriveInput) -> TokenStream {
     }
 }
 
-fn derive_struct(display_name: &str, data: &DataStruct) -> Result<TokenStream, syn::Error> {
-    match &data.fields {
-        Fields::Named(fields) => {
-            let fields = derive_named_fields(fields, true)?;
-            Ok(quote! {
-                f.debug_struct(#display_name)
-                    #fields
-                    .finish()
-            })
-        }
-        Fields::Unnamed(fields) => {
-            let fields = derive_unnamed_fields(fields, true)?;
-            Ok(quote! {
-                f.debug_tuple(#display_name)
-                    #fields
-                    .finish()
-            })
+/// Generates code printing only the name of the type, or the name of the variant for enums.
+fn derive_name_only(
+    display_name: &TokenStream,
+    container: &FieldOutputOptions,
+    data: &syn::Data,
+) -> Result<TokenStream, syn::Error> {
+    let data = match data {
+        syn::Data::Enum(data) if data.variants.is_empty() => {
+            return Ok(quote! { unsafe { ::core::hint::unreachable_unchecked() } })
         }
-        Fields::Unit => Ok(quote! {
-            f.debug_struct(#display_name).finish()
-        }),
-    }
-}
+        syn::Data::Enum(data) => data,
+        _ => return Ok(quote! { f.write_str(#display_name) }),
+    };
 
-fn derive_enum(data: &DataEnum) -> Result<TokenStream, syn::Error> {
-    if data.variants.is_empty() {
-        return Ok(quote! {
-            unsafe { ::core::hint::unreachable_unchecked() }
-        });
+    let mut arms = TokenStream::new();
+    for variant in &data.variants {
+        let name = &variant.ident;
+        let options = parse_options(&variant.attrs, OptionsTarget::EnumVariant)?;
+        let variant_name = derive_variant_name(display_name, name, &options, container);
+        arms.extend(quote! { Self::#name { .. } => f.write_str(#variant_name), });
     }
 
-    let variants = derive_enum_variants(data.variants.iter())?;
-
     Ok(quote! {
         match self {
-            #variants
+            #arms
         }
     })
 }
 
-fn derive_enum_variants<'a>(
-    variants: impl Iterator<Item = &'a Variant>,
+/// Generates the `DEBUG_FIELD_COUNT` constant of a struct, which is the number of fields that are not skipped.
+#[cfg(feature = "testing")]
+fn derive_field_count(
+    item: &DeriveInput,
+    generics: &syn::Generics,
+    container: &FieldOutputOptions,
 ) -> Result<TokenStream, syn::Error> {
-    let mut res = TokenStream::new();
+    let data = match &item.data {
+        syn::Data::Struct(data) => data,
+        _ => return Ok(quote! {}),
+    };
 
-    for variant in variants {
-        let name = &variant.ident;
+    let mut count = 0usize;
+    for field in &data.fields {
+        let options = parse_options(&field.attrs, OptionsTarget::NamedField)?;
+        if !matches!(options.print_type, FieldPrintType::Skip) && !is_hidden(&options, container) {
+            count += 1;
+        }
+    }
 
-        let options = parse_options(&variant.attrs, OptionsTarget::EnumVariant)?;
+    let name = &item.ident;
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics #name #type_generics #where_clause {
+            /// The number of fields printed by the `Debug` implementation.
+            #[allow(dead_code)]
+            pub const DEBUG_FIELD_COUNT: usize = #count;
+        }
+    })
+}
 
-        let display_name = if let Some(alias) = options.alias {
-            alias
-        } else {
-            name.to_string()
-        };
+/// Wraps the output generated by `output` so that it is truncated or padded to the width
+/// requested by the [`Formatter`](std::fmt::Formatter), e.g. `{:20?}`.
+///
+/// If a width is requested, the output is always printed on a single line.
+fn derive_fit_width(output: TokenStream) -> TokenStream {
+    quote! {
+        match f.width() {
+            Some(width) => {
+                let output = ::std::format!(
+                    "{:?}",
+                    DbgFn(|f: &mut __dbg_fmt::Formatter<'_>| { #output })
+                );
+                let output: ::std::string::String = output.chars().take(width).collect();
+                f.pad(&output)
+            }
+            None => { #output }
+        }
+    }
+}
 
-        let derive_variant = match options.print_type {
-            FieldPrintType::Normal => derive_variant(name, &display_name, &variant.fields)?,
-            FieldPrintType::Skip => skip_variant(name, &display_name, &variant.fields)?,
-            _ => return Err(syn::Error::new_spanned(variant, "Internal error")),
-        };
+/// Generates code that prints `<cycle>` if `self` is already being printed further up the stack.
+///
+/// The values currently being printed are tracked in a thread local list of addresses,
+/// together with the type name, as a struct and its first field can share the same address.
+fn derive_cycle_check() -> TokenStream {
+    quote! {
+        ::std::thread_local! {
+            static VISITING: ::std::cell::RefCell<::std::vec::Vec<(usize, &'static str)>> =
+                const { ::std::cell::RefCell::new(::std::vec::Vec::new()) };
+        }
 
-        res.extend(derive_variant);
+        struct CycleGuard((usize, &'static str));
+
+        impl ::std::ops::Drop for CycleGuard {
+            fn drop(&mut self) {
+                VISITING.with(|visiting| {
+                    let mut visiting = visiting.borrow_mut();
+                    if let Some(pos) = visiting.iter().rposition(|key| *key == self.0) {
+                        visiting.remove(pos);
+                    }
+                });
+            }
+        }
+
+        let key = (
+            self as *const Self as *const () as usize,
+            ::std::any::type_name::<Self>(),
+        );
+        if VISITING.with(|visiting| visiting.borrow().contains(&key)) {
+            return f.write_str("<cycle>");
+        }
+        VISITING.with(|visiting| visiting.borrow_mut().push(key));
+        let _guard = CycleGuard(key);
     }
+}
 
-    Ok(res)
+/// Generates the runtime check deciding whether a `#[dbg(color)]` item prints escape sequences.
+///
+/// Colors are disabled if `NO_COLOR` is set and otherwise enabled if `CLICOLOR_FORCE` is set
+/// or stdout is a terminal, so output piped to a file stays plain.
+fn derive_color_check() -> TokenStream {
+    quote! {
+        let __dbg_color = !matches!(::std::env::var_os("NO_COLOR"), Some(v) if !v.is_empty())
+            && (matches!(::std::env::var_os("CLICOLOR_FORCE"), Some(v) if !v.is_empty() && v != "0")
+                || ::std::io::IsTerminal::is_terminal(&::std::io::stdout()));
+    }
 }
 
-fn derive_variant(
+/// Wraps the name expression `name` in ANSI escape sequences if colors are enabled at runtime.
+fn derive_colored_name(name: TokenStream) -> TokenStream {
+    quote! {
+        &if __dbg_color {
+            ::std::borrow::Cow::<str>::Owned(::std::format!("\x1b[36m{}\x1b[0m", #name))
+        } else {
+            ::std::borrow::Cow::<str>::Borrowed(#name)
+        }
+    }
+}
+
+/// Generates the `DbgFn` adapter type that is emitted into every generated `fmt` function.
+///
+/// It wraps a closure so that arbitrary formatting code can be passed to the
+/// [`DebugStruct`](std::fmt::DebugStruct)/[`DebugTuple`](std::fmt::DebugTuple) builders as a field value.
+fn debug_fn_adapter() -> TokenStream {
+    quote! {
+        #[allow(dead_code)]
+        struct DbgFn<F>(F)
+        where
+            F: Fn(&mut __dbg_fmt::Formatter<'_>) -> __dbg_fmt::Result;
+
+        impl<F> __dbg_fmt::Debug for DbgFn<F>
+        where
+            F: Fn(&mut __dbg_fmt::Formatter<'_>) -> __dbg_fmt::Result,
+        {
+            fn fmt(&self, f: &mut __dbg_fmt::Formatter<'_>) -> __dbg_fmt::Result {
+                (self.0)(f)
+            }
+        }
+    }
+}
+
+fn derive_struct(
     name: &Ident,
-    display_name: &str,
-    fields: &Fields,
+    display_name: &TokenStream,
+    container: &FieldOutputOptions,
+    data: &DataStruct,
 ) -> Result<TokenStream, syn::Error> {
-    let match_list = derive_match_list(fields)?;
+    if let (Some(order), Fields::Unnamed(_) | Fields::Unit) = (&container.order, &data.fields) {
+        return Err(syn::Error::new_spanned(
+            order,
+            "order is only supported on structs with named fields",
+        ));
+    }
 
-    match fields {
+    if let (Some(group), Fields::Unnamed(_) | Fields::Unit) =
+        (container.groups.first(), &data.fields)
+    {
+        return Err(syn::Error::new_spanned(
+            &group.name,
+            "group is only supported on structs with named fields",
+        ));
+    }
+
+    if let (Some(version), Fields::Unnamed(_)) = (&container.version, &data.fields) {
+        return Err(syn::Error::new_spanned(
+            version,
+            "version is only supported on structs with named fields",
+        ));
+    }
+
+    if let (Some(size), Fields::Unnamed(_)) = (&container.show_size, &data.fields) {
+        return Err(syn::Error::new_spanned(
+            size,
+            "show_size is only supported on structs with named fields",
+        ));
+    }
+
+    if container.transparent {
+        return derive_transparent(name, container, data);
+    }
+
+    let version = derive_version_field(container);
+    let size = derive_size_field(container);
+    let non_exhaustive = container.non_exhaustive || hides_fields(&data.fields, container)?;
+
+    match &data.fields {
         Fields::Named(fields) => {
-            let fields = derive_named_fields(fields, false)?;
-            Ok(quote! {
-                Self::#name #match_list => f.debug_struct(#display_name) #fields .finish(),
-            })
+            let fields = match &container.order {
+                Some(order) => order_fields(fields, order)?,
+                None => fields.clone(),
+            };
+            let fields = if container.groups.is_empty() {
+                derive_named_fields(&fields, true, container)?
+            } else {
+                derive_grouped_fields(&fields, container)?
+            };
+            let fields: Vec<_> = version.into_iter().chain(fields).chain(size).collect();
+            Ok(derive_fields_output(
+                display_name,
+                FieldsStyle::Named,
+                &fields,
+                non_exhaustive,
+                container,
+            ))
         }
         Fields::Unnamed(fields) => {
-            let fields = derive_unnamed_fields(fields, false)?;
-            Ok(quote! {
-                Self::#name #match_list => f.debug_tuple(#display_name) #fields .finish(),
-            })
+            let fields = derive_unnamed_fields(fields, true, container)?;
+            Ok(derive_fields_output(
+                display_name,
+                unnamed_fields_style(&fields),
+                &fields,
+                non_exhaustive,
+                container,
+            ))
         }
-        Fields::Unit => Ok(quote! { Self::#name => write!(f, #display_name), }),
+        Fields::Unit => Ok(derive_fields_output(
+            display_name,
+            FieldsStyle::Named,
+            &version.into_iter().chain(size).collect::<Vec<_>>(),
+            non_exhaustive,
+            container,
+        )),
     }
 }
 
-fn skip_variant(
+/// Generates the pseudo-field printing the value of the `version` option, named `_schema` by default.
+fn derive_version_field(container: &FieldOutputOptions) -> Option<FieldEntry> {
+    let version = container.version.as_ref()?;
+    let name = match &container.version_field {
+        Some(name) => quote! { #name },
+        None => quote! { "_schema" },
+    };
+
+    Some(FieldEntry {
+        name: Some(name),
+        value: quote! { &#version },
+        condition: None,
+        expand: false,
+    })
+}
+
+/// Generates the trailing field containing the size of the struct, if `show_size` is set.
+fn derive_size_field(container: &FieldOutputOptions) -> Option<FieldEntry> {
+    let name = container.show_size.as_ref()?;
+
+    Some(FieldEntry {
+        name: Some(quote! { #name }),
+        value: quote! { &::core::mem::size_of::<Self>() },
+        condition: None,
+        expand: false,
+    })
+}
+
+/// Generates the output of a `transparent` struct, which is printed exactly like its only field.
+///
+/// A field containing the struct itself, e.g. `Box<Self>`, is rejected if it is printed using `Debug`,
+/// as printing it would recurse until the stack overflows.
+fn derive_transparent(
     name: &Ident,
-    display_name: &str,
-    fields: &Fields,
+    container: &FieldOutputOptions,
+    data: &DataStruct,
 ) -> Result<TokenStream, syn::Error> {
-    match fields {
-        Fields::Named(_) => {
-            Ok(quote! { Self::#name{..} => f.debug_struct(#display_name).finish(), })
-        }
-        Fields::Unnamed(_) => {
-            Ok(quote! { Self::#name(..) => f.debug_tuple(#display_name).finish(), })
+    for field in &data.fields {
+        let target = match field.ident {
+            Some(_) => OptionsTarget::NamedField,
+            None => OptionsTarget::UnnamedField,
+        };
+        let options = parse_field_options(field, target, container)?;
+        if matches!(options.print_type, FieldPrintType::Normal)
+            && !is_hidden(&options, container)
+            && contains_self(&field.ty, name)
+        {
+            return Err(syn::Error::new_spanned(
+                &field.ty,
+                "transparent can not print a field containing the struct itself, as it would recurse infinitely",
+            ));
         }
-        Fields::Unit => Ok(quote! { Self::#name => write!(f, #display_name), }),
     }
-}
 
-fn derive_match_list(fields: &Fields) -> Result<TokenStream, syn::Error> {
-    match fields {
-        Fields::Named(fields) => {
-            let mut res = TokenStream::new();
-            for field in &fields.named {
-                let name = field.ident.as_ref().unwrap();
-                let options = parse_options(&field.attrs, OptionsTarget::NamedField)?;
+    let fields = match &data.fields {
+        Fields::Named(fields) => derive_named_fields(fields, true, container)?,
+        Fields::Unnamed(fields) => derive_unnamed_fields(fields, true, container)?,
+        Fields::Unit => Vec::new(),
+    };
 
-                match options.print_type {
-                    FieldPrintType::Skip => res.extend(quote! { #name: _, }),
-                    _ => res.extend(quote! { #name, }),
-                }
-            }
-            Ok(quote! { { #res } })
-        }
-        Fields::Unnamed(fields) => {
-            let mut res = TokenStream::new();
-            for (i, field) in fields.unnamed.iter().enumerate() {
-                let name = format_ident!("field_{}", i);
-                let options = parse_options(&field.attrs, OptionsTarget::UnnamedField)?;
+    match fields.as_slice() {
+        [FieldEntry {
+            value,
+            condition: None,
+            expand: false,
+            ..
+        }] => Ok(quote! { __dbg_fmt::Debug::fmt(#value, f) }),
+        [FieldEntry {
+            condition: Some(_), ..
+        }] => Err(syn::Error::new_spanned(
+            &data.fields,
+            "transparent does not support skip_if",
+        )),
+        [FieldEntry { expand: true, .. }] => Err(syn::Error::new_spanned(
+            &data.fields,
+            "transparent does not support expand_with",
+        )),
+        _ => Err(syn::Error::new_spanned(
+            &data.fields,
+            "transparent requires exactly one field that is not skipped",
+        )),
+    }
+}
 
-                match options.print_type {
-                    FieldPrintType::Skip => res.extend(quote! { _, }),
-                    _ => res.extend(quote! { #name, }),
+/// Returns whether `ty` is the struct called `name` itself, possibly behind a `Box` or a reference.
+fn contains_self(ty: &Type, name: &Ident) -> bool {
+    match ty {
+        Type::Reference(reference) => contains_self(&reference.elem, name),
+        Type::Paren(paren) => contains_self(&paren.elem, name),
+        Type::Path(path) if path.qself.is_none() => {
+            let Some(last) = path.path.segments.last() else {
+                return false;
+            };
+            match &last.arguments {
+                _ if path.path.segments.len() == 1
+                    && (last.ident == "Self" || last.ident == *name) =>
+                {
+                    true
+                }
+                syn::PathArguments::AngleBracketed(args) if last.ident == "Box" => {
+                    matches!(
+                        args.args.first(),
+                        Some(syn::GenericArgument::Type(inner)) if contains_self(inner, name)
+                    )
                 }
+                _ => false,
             }
-            Ok(quote! { (#res) })
         }
-        Fields::Unit => Ok(quote! {}),
+        _ => false,
     }
 }
 
-fn derive_named_fields(fields: &FieldsNamed, use_self: bool) -> Result<TokenStream, syn::Error> {
-    let mut res = TokenStream::new();
+/// Generates the fields of a struct with `group` options.
+///
+/// Every group is printed like a nested struct at the position of its first field,
+/// its members are printed in the order listed in the group.
+fn derive_grouped_fields(
+    fields: &FieldsNamed,
+    container: &FieldOutputOptions,
+) -> Result<Vec<FieldEntry>, syn::Error> {
+    // maps every grouped field to the index of its top-level group
+    let mut grouped = std::collections::BTreeMap::new();
+    for (index, group) in container.groups.iter().enumerate() {
+        let mut error = None;
+        group.for_each_field(&mut |name| {
+            let known = fields
+                .named
+                .iter()
+                .any(|field| field.ident.as_ref().unwrap() == &name.value());
+            if (!known || grouped.insert(name.value(), index).is_some()) && error.is_none() {
+                error = Some(syn::Error::new_spanned(
+                    name,
+                    format!("unknown or duplicate field `{}`", name.value()),
+                ));
+            }
+        });
+        if let Some(error) = error {
+            return Err(error);
+        }
+    }
 
+    let mut res = Vec::new();
+    let mut emitted = vec![false; container.groups.len()];
     for field in &fields.named {
-        let name = field.ident.as_ref().unwrap();
-
-        let options = parse_options(&field.attrs, OptionsTarget::NamedField)?;
+        match grouped.get(&field.ident.as_ref().unwrap().to_string()) {
+            Some(&index) if !emitted[index] => {
+                emitted[index] = true;
+                res.push(derive_group(&container.groups[index], fields, container)?);
+            }
+            Some(_) => {}
+            None => res.extend(derive_named_fields(
+                &single_field(fields, field),
+                true,
+                container,
+            )?),
+        }
+    }
 
-        let name_str = if let Some(alias) = options.alias {
-            alias
-        } else {
-            name.to_string()
-        };
+    Ok(res)
+}
 
-        match options.print_type {
-            FieldPrintType::Normal => {
-                let field_ref = if use_self {
-                    quote! { &self.#name }
-                } else {
-                    quote! { #name }
-                };
-                res.extend(quote! { .field(#name_str, #field_ref) });
-            }
-            FieldPrintType::Placeholder(placeholder) => {
-                res.extend(quote! { .field(#name_str, &format_args!(#placeholder)) })
+/// Generates a single entry printing the fields of `group` as `name { a: 1, .. }`.
+fn derive_group(
+    group: &FieldGroup,
+    fields: &FieldsNamed,
+    container: &FieldOutputOptions,
+) -> Result<FieldEntry, syn::Error> {
+    let mut entries = Vec::new();
+    for item in &group.items {
+        match item {
+            GroupItem::Field(name) => {
+                // the names have been validated by `derive_grouped_fields`
+                let field = fields
+                    .named
+                    .iter()
+                    .find(|field| field.ident.as_ref().unwrap() == &name.value())
+                    .unwrap();
+                entries.extend(derive_named_fields(
+                    &single_field(fields, field),
+                    true,
+                    container,
+                )?);
             }
-            FieldPrintType::Format(fmt) => {
-                let field_ref = if use_self {
-                    quote! { self.#name }
-                } else {
-                    quote! { #name }
-                };
-                res.extend(quote! { .field(#name_str, &format_args!(#fmt, #field_ref)) })
-            }
-            FieldPrintType::Custom(formatter) => {
-                let field_ref = if use_self {
-                    quote! { &self.#name }
-                } else {
-                    quote! { #name }
-                };
-                res.extend(quote! { .field(#name_str, &format_args!("{}", #formatter(#field_ref))) })
-            }
-            FieldPrintType::Skip => {}
+            GroupItem::Group(group) => entries.push(derive_group(group, fields, container)?),
         }
     }
 
-    Ok(res)
+    let name = &group.name;
+    let entries = entries
+        .iter()
+        .map(|entry| entry.print(|name, value| quote! { builder.field(#name, #value); }));
+
+    Ok(FieldEntry {
+        name: Some(quote! { #name }),
+        value: quote! {
+            &DbgFn(|f: &mut __dbg_fmt::Formatter<'_>| {
+                let mut builder = f.debug_struct(#name);
+                #(#entries)*
+                builder.finish()
+            })
+        },
+        condition: None,
+        expand: false,
+    })
 }
 
-fn derive_unnamed_fields(
-    fields: &FieldsUnnamed,
-    use_self: bool,
+/// Returns a copy of `fields` only containing `field`.
+fn single_field(fields: &FieldsNamed, field: &syn::Field) -> FieldsNamed {
+    FieldsNamed {
+        brace_token: fields.brace_token,
+        named: std::iter::once(field.clone()).collect(),
+    }
+}
+
+/// Generates the output of a union, which is printed as `Name { .. }`.
+///
+/// Only the field selected by the `field` option is printed, reading it is the responsibility of the user.
+fn derive_union(
+    display_name: &TokenStream,
+    container: &FieldOutputOptions,
+    data: &DataUnion,
 ) -> Result<TokenStream, syn::Error> {
-    let mut res = TokenStream::new();
+    let field = match &container.union_field {
+        Some(name) => {
+            let field = data
+                .fields
+                .named
+                .iter()
+                .find(|field| field.ident.as_ref().unwrap() == &name.value())
+                .ok_or_else(|| syn::Error::new_spanned(name, "unknown field"))?;
 
-    for (i, field) in fields.unnamed.iter().enumerate() {
-        let options = parse_options(&field.attrs, OptionsTarget::UnnamedField)?;
+            derive_named_fields(&single_field(&data.fields, field), true, container)?
+        }
+        None => Vec::new(),
+    };
 
-        match options.print_type {
-            FieldPrintType::Normal => {
-                let field_ref = if use_self {
-                    let index = syn::Index::from(i);
-                    quote! { &self.#index }
-                } else {
-                    format_ident!("field_{}", i).to_token_stream()
-                };
-                res.extend(quote! { .field(#field_ref) });
-            }
-            FieldPrintType::Placeholder(placeholder) => {
-                res.extend(quote! { .field(&format_args!(#placeholder)) })
-            }
-            FieldPrintType::Format(fmt) => {
-                let field_ref = if use_self {
-                    let index = syn::Index::from(i);
-                    quote! { self.#index }
-                } else {
-                    format_ident!("field_{}", i).to_token_stream()
-                };
-                res.extend(quote! { .field(&format_args!(#fmt, #field_ref)) })
-            }
-            FieldPrintType::Custom(formatter) => {
-                let field_ref = if use_self {
-                    let index = syn::Index::from(i);
-                    quote! { &self.#index }
-                } else {
-                    format_ident!("field_{}", i).to_token_stream()
-                };
-                res.extend(quote! { .field(&format_args!("{}", #formatter(#field_ref))) });
+    let output = derive_fields_output(display_name, FieldsStyle::Named, &field, true, container);
+
+    if container.union_field.is_some() {
+        Ok(quote! {
+            #[allow(unused_unsafe)]
+            unsafe { #output }
+        })
+    } else {
+        Ok(output)
+    }
+}
+
+/// Reorders `fields` as given by the comma separated list of field names in `order`.
+///
+/// Fields missing from the list are appended in declaration order.
+fn order_fields(fields: &FieldsNamed, order: &LitStr) -> Result<FieldsNamed, syn::Error> {
+    let mut remaining: Vec<_> = fields.named.iter().collect();
+    let mut res = FieldsNamed {
+        brace_token: fields.brace_token,
+        named: Default::default(),
+    };
+
+    for name in order.value().split(',').map(str::trim) {
+        match remaining
+            .iter()
+            .position(|field| field.ident.as_ref().unwrap() == name)
+        {
+            Some(index) => res.named.push(remaining.remove(index).clone()),
+            None => {
+                return Err(syn::Error::new(
+                    order.span(),
+                    format!("unknown or duplicate field `{}`", name),
+                ))
             }
-            FieldPrintType::Skip => {}
         }
     }
+    res.named.extend(remaining.into_iter().cloned());
 
     Ok(res)
 }
 
-enum FieldPrintType {
-    Normal,
-    Placeholder(String),
-    Skip,
-    Format(LitStr),
-    Custom(Path),
-}
+fn derive_enum(
+    display_name: &TokenStream,
+    container: &FieldOutputOptions,
+    data: &DataEnum,
+) -> Result<TokenStream, syn::Error> {
+    if data.variants.is_empty() {
+        return Ok(quote! {
+            unsafe { ::core::hint::unreachable_unchecked() }
+        });
+    }
 
-struct FieldOutputOptions {
-    print_type: FieldPrintType,
-    alias: Option<String>,
+    let fieldless = data
+        .variants
+        .iter()
+        .all(|variant| matches!(variant.fields, Fields::Unit));
+    let variants = derive_enum_variants(display_name, container, data.variants.iter(), fieldless)?;
+
+    Ok(quote! {
+        match self {
+            #variants
+        }
+    })
 }
 
-#[derive(PartialEq, Eq)]
-enum OptionsTarget {
-    DeriveItem,
-    EnumVariant,
-    NamedField,
-    UnnamedField,
+fn derive_enum_variants<'a>(
+    enum_display_name: &TokenStream,
+    container: &FieldOutputOptions,
+    variants: impl Iterator<Item = &'a Variant>,
+    fieldless: bool,
+) -> Result<TokenStream, syn::Error> {
+    let mut res = TokenStream::new();
+
+    for variant in variants {
+        let name = &variant.ident;
+
+        let options = parse_options(&variant.attrs, OptionsTarget::EnumVariant)?;
+        let display_name = derive_variant_name(enum_display_name, name, &options, container);
+
+        if let Some(names) = &container.names_from {
+            if !matches!(variant.fields, Fields::Unit) {
+                return Err(syn::Error::new_spanned(
+                    variant,
+                    "names_from is only supported on enums without fields",
+                ));
+            }
+
+            // enums without fields can be cast to their discriminant without requiring Copy
+            res.extend(quote! {
+                Self::#name => match #names.get(Self::#name as usize) {
+                    Some(name) => __dbg_fmt::Display::fmt(name, f),
+                    None => f.write_str(#display_name),
+                },
+            });
+            continue;
+        }
+
+        if container.discriminant
+            && matches!(variant.fields, Fields::Unit)
+            && matches!(options.print_type, FieldPrintType::Normal)
+            && !options.silent
+        {
+            let discriminant = derive_discriminant(variant, container, fieldless)?;
+            res.extend(quote! {
+                Self::#name => write!(f, "{}({})", #display_name, #discriminant),
+            });
+            continue;
+        }
+
+        let derive_variant = match options.print_type {
+            _ if options.silent => silent_variant(name, &variant.fields),
+            _ if options.catch_all => catch_all_variant(variant, container)?,
+            FieldPrintType::Normal => {
+                derive_variant(name, &display_name, &variant.fields, &options, container)?
+            }
+            FieldPrintType::Skip => skip_variant(name, &display_name, &variant.fields)?,
+            _ => return Err(syn::Error::new_spanned(variant, "Internal error")),
+        };
+
+        res.extend(derive_variant);
+    }
+
+    Ok(res)
 }
 
-fn parse_options(
-    attributes: &[Attribute],
-    target: OptionsTarget,
-) -> Result<FieldOutputOptions, syn::Error> {
-    let mut res = FieldOutputOptions {
-        print_type: FieldPrintType::Normal,
-        alias: None,
+/// Generates an expression evaluating to the displayed name of the variant `name`.
+fn derive_variant_name(
+    enum_display_name: &TokenStream,
+    name: &Ident,
+    options: &FieldOutputOptions,
+    container: &FieldOutputOptions,
+) -> TokenStream {
+    let prefix = match container.variant_style.unwrap_or(VariantStyle::Short) {
+        VariantStyle::Short => None,
+        VariantStyle::Qualified => Some(quote! { concat!(#enum_display_name, "::") }),
     };
 
-    for attrib in attributes {
-        if !attrib.path.is_ident("dbg") {
-            continue;
+    let display_name = match (&options.alias, &prefix) {
+        (Some(alias), Some(prefix)) => quote! { concat!(#prefix, #alias) },
+        (Some(alias), None) => quote! { #alias },
+        (None, _) => derive_name(prefix.as_ref(), &name.unraw().to_string(), container),
+    };
+    derive_translated_name(display_name, container)
+}
+
+/// Generates an expression evaluating to the discriminant of the given unit variant.
+///
+/// Fieldless enums are simply cast to their `repr` type. The discriminant of enums with fields
+/// can only be read if they have a primitive representation, which places it at the start of the enum.
+fn derive_discriminant(
+    variant: &Variant,
+    container: &FieldOutputOptions,
+    fieldless: bool,
+) -> Result<TokenStream, syn::Error> {
+    let name = &variant.ident;
+
+    match (&container.repr, fieldless) {
+        (Some(repr), true) => Ok(quote! { Self::#name as #repr }),
+        (None, true) => Ok(quote! { Self::#name as isize }),
+        // SAFETY: see https://doc.rust-lang.org/reference/items/enumerations.html#pointer-casting
+        (Some(repr), false) => Ok(quote! {
+            unsafe { *(self as *const Self).cast::<#repr>() }
+        }),
+        (None, false) => Err(syn::Error::new_spanned(
+            variant,
+            "discriminant requires a primitive representation like #[repr(u8)] on enums with fields",
+        )),
+    }
+}
+
+fn derive_variant(
+    name: &Ident,
+    display_name: &TokenStream,
+    fields: &Fields,
+    options: &FieldOutputOptions,
+    container: &FieldOutputOptions,
+) -> Result<TokenStream, syn::Error> {
+    let match_list = derive_match_list(fields, container)?;
+    let non_exhaustive =
+        options.non_exhaustive || container.non_exhaustive || hides_fields(fields, container)?;
+
+    let (output, name_only) = match fields {
+        Fields::Named(fields) => {
+            let fields = derive_named_fields(fields, false, container)?;
+            let output = derive_fields_output(
+                display_name,
+                FieldsStyle::Named,
+                &fields,
+                non_exhaustive,
+                container,
+            );
+            (output, quote! { f.debug_struct(#display_name).finish() })
         }
+        Fields::Unnamed(fields) => {
+            let fields = derive_unnamed_fields(fields, false, container)?;
+            let output = derive_fields_output(
+                display_name,
+                unnamed_fields_style(&fields),
+                &fields,
+                non_exhaustive,
+                container,
+            );
+            (output, quote! { f.debug_tuple(#display_name).finish() })
+        }
+        Fields::Unit => return Ok(quote! { Self::#name => f.write_str(#display_name), }),
+    };
 
-        let meta = attrib.parse_meta()?;
-        let meta = if let Meta::List(m) = meta {
-            m
+    // skip_if receives the whole enum, so the decision is made at runtime inside the arm
+    let output = match &options.skip_if {
+        Some(skip_if) => quote! {
+            if #skip_if(self) {
+                #name_only
+            } else {
+                #output
+            }
+        },
+        None => output,
+    };
+
+    Ok(quote! {
+        Self::#name #match_list => { #output }
+    })
+}
+
+/// Generates an expression evaluating to the displayed name of a field or variant called `name`,
+/// prefixed by `prefix`, which has to be a string literal or a macro call expanding to one.
+///
+/// If the container specifies `rename_all`, `name` is converted to that case.
+/// If it specifies `rename_all_with`, the function is applied to `name` at runtime.
+fn derive_name(
+    prefix: Option<&TokenStream>,
+    name: &str,
+    container: &FieldOutputOptions,
+) -> TokenStream {
+    let renamed;
+    let name = match container.rename_all {
+        Some(rule) => {
+            renamed = rule.apply(name);
+            renamed.as_str()
+        }
+        None => name,
+    };
+
+    match (&container.rename_all_with, prefix) {
+        (Some(rename), Some(prefix)) => quote! { &::std::format!("{}{}", #prefix, #rename(#name)) },
+        (Some(rename), None) => quote! { &#rename(#name) },
+        (None, Some(prefix)) => quote! { concat!(#prefix, #name) },
+        (None, None) => quote! { #name },
+    }
+}
+
+/// Builds the type name from the `name_concat` items, including the affixes and the crate prefix.
+fn derive_concat_name(parts: &[NamePart], container: &FieldOutputOptions) -> TokenStream {
+    let crate_prefix = container
+        .crate_prefixed
+        .then(|| NamePart::Lit(quote! { env!("CARGO_PKG_NAME"), "::" }));
+    let prefix = container
+        .alias_prefix
+        .as_ref()
+        .map(|prefix| NamePart::Lit(quote! { #prefix }));
+    let suffix = container
+        .alias_suffix
+        .as_ref()
+        .map(|suffix| NamePart::Lit(quote! { #suffix }));
+
+    let parts: Vec<_> = crate_prefix
+        .into_iter()
+        .chain(prefix)
+        .chain(parts.iter().cloned())
+        .chain(suffix)
+        .collect();
+
+    name_concat::derive_concat(&parts)
+}
+
+/// Passes the expression `name` evaluating to a displayed name through the `i18n` function of the container, if any.
+fn derive_translated_name(name: TokenStream, container: &FieldOutputOptions) -> TokenStream {
+    match &container.i18n {
+        Some(translate) => quote! { #translate(#name) },
+        None => name,
+    }
+}
+
+#[derive(Clone, Copy)]
+enum FieldsStyle {
+    Named,
+    Unnamed,
+}
+
+/// A single field as it appears in the output.
+struct FieldEntry {
+    /// Expression evaluating to the displayed name, `None` for unnamed fields.
+    name: Option<TokenStream>,
+    /// Expression evaluating to a reference to something implementing [`Debug`](std::fmt::Debug).
+    value: TokenStream,
+    /// Expression evaluating to `true` if the field should be printed, `None` if it is always printed.
+    condition: Option<TokenStream>,
+    /// If set, `value` evaluates to an iterator of `(name, value)` pairs that are printed as separate fields,
+    /// the values using [`Display`](std::fmt::Display).
+    expand: bool,
+}
+
+impl FieldEntry {
+    /// Generates the code printing this entry, using `field` to generate the code printing a single field.
+    ///
+    /// `field` receives the expressions evaluating to the field's name and value.
+    fn print(
+        &self,
+        field: impl Fn(Option<&TokenStream>, &TokenStream) -> TokenStream,
+    ) -> TokenStream {
+        let res = if self.expand {
+            let value = &self.value;
+            let field = field(
+                Some(&quote! { expanded_name }),
+                &quote! { &format_args!("{}", expanded_value) },
+            );
+            quote! {
+                for (expanded_name, expanded_value) in #value {
+                    #field
+                }
+            }
         } else {
-            return Err(syn::Error::new_spanned(
-                meta,
-                "invalid #[dbg(...)] attribute",
-            ));
+            field(self.name.as_ref(), &self.value)
         };
 
-        for option in meta.nested {
-            match option {
-                NestedMeta::Meta(Meta::Path(option))
-                    if option.is_ident("skip") && target != OptionsTarget::DeriveItem =>
+        match &self.condition {
+            Some(condition) => quote! { if #condition { #res } },
+            None => res,
+        }
+    }
+}
+
+/// Generates the code printing `display_name` followed by the given fields.
+///
+/// If `non_exhaustive` is set, the output is finished with `..`
+/// using [`finish_non_exhaustive`](std::fmt::DebugStruct::finish_non_exhaustive).
+///
+/// The callers set `non_exhaustive` if the `non_exhaustive` option is used, which always prints `..`,
+/// or if at least one field is skipped or hidden by `opt_in`, see [`hides_fields`]:
+///
+/// | `non_exhaustive` option | skipped fields | output          |
+/// |-------------------------|----------------|-----------------|
+/// | no                      | no             | `Foo { a }`     |
+/// | no                      | yes            | `Foo { a, .. }` |
+/// | yes                     | no             | `Foo { a, .. }` |
+/// | yes                     | yes            | `Foo { a, .. }` |
+///
+/// If `non_exhaustive` is not set, the container option `non_exhaustive_if` decides at runtime.
+/// The builder calls are then split into statements, so that the finishing call can be chosen afterwards.
+fn derive_fields_output(
+    display_name: &TokenStream,
+    style: FieldsStyle,
+    fields: &[FieldEntry],
+    non_exhaustive: bool,
+    container: &FieldOutputOptions,
+) -> TokenStream {
+    // `non_exhaustive` always wins over a runtime condition
+    let non_exhaustive_if = match non_exhaustive {
+        true => None,
+        false => container.non_exhaustive_if.as_ref(),
+    };
+
+    if container.best_effort {
+        return derive_best_effort_output(
+            display_name,
+            style,
+            fields,
+            non_exhaustive,
+            non_exhaustive_if,
+        );
+    }
+
+    if container.logfmt {
+        return derive_logfmt_output(display_name, fields, non_exhaustive, non_exhaustive_if);
+    }
+
+    if container.dedup_fields && matches!(style, FieldsStyle::Named) {
+        return derive_dedup_output(display_name, fields, non_exhaustive, non_exhaustive_if);
+    }
+
+    let builder = match style {
+        FieldsStyle::Named => quote! { f.debug_struct(#display_name) },
+        FieldsStyle::Unnamed => quote! { f.debug_tuple(#display_name) },
+    };
+
+    let finish = if non_exhaustive {
+        quote! { .finish_non_exhaustive() }
+    } else {
+        quote! { .finish() }
+    };
+
+    // the builder calls are only split into statements if some fields are printed conditionally or expanded,
+    // or the finishing call is chosen at runtime
+    if fields
+        .iter()
+        .all(|field| field.condition.is_none() && !field.expand)
+        && non_exhaustive_if.is_none()
+    {
+        let mut res = builder;
+        for FieldEntry { name, value, .. } in fields {
+            match name {
+                Some(name) => res.extend(quote! { .field(#name, #value) }),
+                None => res.extend(quote! { .field(#value) }),
+            }
+        }
+        res.extend(finish);
+        return res;
+    }
+
+    let mut res = quote! { let mut builder = #builder; };
+    for entry in fields {
+        res.extend(entry.print(|name, value| match name {
+            Some(name) => quote! { builder.field(#name, #value); },
+            None => quote! { builder.field(#value); },
+        }));
+    }
+    match non_exhaustive_if {
+        Some(condition) => res.extend(quote! {
+            if #condition {
+                builder.finish_non_exhaustive()
+            } else {
+                builder.finish()
+            }
+        }),
+        None => res.extend(quote! { builder #finish }),
+    }
+    quote! { { #res } }
+}
+
+/// Generates code writing the fields manually instead of using the [`Formatter`](std::fmt::Formatter) builders.
+///
+/// Errors returned while printing a field's value are replaced by `<error>`,
+/// only errors while writing the surrounding structure are returned.
+/// The output is always printed on a single line, even for `{:#?}`.
+fn derive_best_effort_output(
+    display_name: &TokenStream,
+    style: FieldsStyle,
+    fields: &[FieldEntry],
+    non_exhaustive: bool,
+    non_exhaustive_if: Option<&Expr>,
+) -> TokenStream {
+    let (open, close) = match style {
+        FieldsStyle::Named => (" { ", " }"),
+        FieldsStyle::Unnamed => ("(", ")"),
+    };
+
+    // the braces are only written once the first field is printed, which may depend on conditions
+    let mut res = quote! {
+        f.write_str(#display_name)?;
+        let first = ::core::cell::Cell::new(true);
+    };
+    let separator = quote! {
+        f.write_str(if first.replace(false) { #open } else { ", " })?;
+    };
+
+    for entry in fields {
+        res.extend(entry.print(|name, value| {
+            let mut field = separator.clone();
+            if let Some(name) = name {
+                field.extend(quote! {
+                    f.write_str(#name)?;
+                    f.write_str(": ")?;
+                });
+            }
+            field.extend(quote! {
+                if __dbg_fmt::Debug::fmt(#value, f).is_err() {
+                    f.write_str("<error>")?;
+                }
+            });
+            field
+        }));
+    }
+
+    let rest = quote! {
+        #separator
+        f.write_str("..")?;
+    };
+    match (non_exhaustive, non_exhaustive_if) {
+        (true, _) => res.extend(rest),
+        (false, Some(condition)) => res.extend(quote! { if #condition { #rest } }),
+        (false, None) => {}
+    }
+
+    res.extend(quote! {
+        if !first.get() {
+            f.write_str(#close)?;
+        }
+        Ok(())
+    });
+    res
+}
+
+/// Generates code writing the fields as `Name key1=value1 key2=value2` pairs in the logfmt style.
+///
+/// Values are printed using [`Debug`](std::fmt::Debug) and quoted if they contain whitespace, `"` or `=`.
+/// The quotes of strings are removed if they are not needed. Unnamed fields use their index as key,
+/// which is set by [`derive_unnamed_fields`].
+fn derive_logfmt_output(
+    display_name: &TokenStream,
+    fields: &[FieldEntry],
+    non_exhaustive: bool,
+    non_exhaustive_if: Option<&Expr>,
+) -> TokenStream {
+    let mut res = quote! { f.write_str(#display_name)?; };
+
+    for entry in fields {
+        res.extend(entry.print(|name, value| {
+            let name = name.expect("logfmt fields are always named");
+            quote! {{
+                let value = ::std::format!("{:?}", #value);
+                let is_str = value.len() >= 2 && value.starts_with('"') && value.ends_with('"');
+                let unquoted = if is_str { &value[1..value.len() - 1] } else { value.as_str() };
+                if !unquoted.is_empty()
+                    && !unquoted.contains(|c: char| c.is_whitespace() || c == '"' || c == '=')
+                {
+                    write!(f, " {}={}", #name, unquoted)?;
+                } else if is_str {
+                    write!(f, " {}={}", #name, value)?;
+                } else {
+                    write!(f, " {}={:?}", #name, value)?;
+                }
+            }}
+        }));
+    }
+
+    match (non_exhaustive, non_exhaustive_if) {
+        (true, _) => res.extend(quote! { f.write_str(" ..")?; }),
+        (false, Some(condition)) => res.extend(quote! {
+            if #condition {
+                f.write_str(" ..")?;
+            }
+        }),
+        (false, None) => {}
+    }
+
+    res.extend(quote! { Ok(()) });
+    res
+}
+
+/// Generates code writing the fields on a single line, collapsing consecutive fields with the same value
+/// into `a, b, c: value`.
+///
+/// The values are compared by their printed output, so the fields don't have to implement [`PartialEq`].
+fn derive_dedup_output(
+    display_name: &TokenStream,
+    fields: &[FieldEntry],
+    non_exhaustive: bool,
+    non_exhaustive_if: Option<&Expr>,
+) -> TokenStream {
+    // all printed fields are collected first, as runs of equal values are only known afterwards
+    let mut res = quote! {
+        let mut fields: ::std::vec::Vec<(::std::string::String, ::std::string::String)> =
+            ::std::vec::Vec::new();
+    };
+    for entry in fields {
+        res.extend(entry.print(|name, value| {
+            quote! {
+                fields.push((::std::format!("{}", #name), ::std::format!("{:?}", #value)));
+            }
+        }));
+    }
+
+    let non_exhaustive = match (non_exhaustive, non_exhaustive_if) {
+        (true, _) => quote! { true },
+        (false, Some(condition)) => quote! { #condition },
+        (false, None) => quote! { false },
+    };
+
+    res.extend(quote! {
+        f.write_str(#display_name)?;
+        let mut start = 0;
+        while start < fields.len() {
+            f.write_str(if start == 0 { " { " } else { ", " })?;
+            let mut end = start;
+            while end + 1 < fields.len() && fields[end + 1].1 == fields[start].1 {
+                f.write_str(&fields[end].0)?;
+                f.write_str(", ")?;
+                end += 1;
+            }
+            write!(f, "{}: {}", fields[end].0, fields[end].1)?;
+            start = end + 1;
+        }
+        if #non_exhaustive {
+            f.write_str(if fields.is_empty() { " { .." } else { ", .." })?;
+        }
+        if !fields.is_empty() || #non_exhaustive {
+            f.write_str(" }")?;
+        }
+        Ok(())
+    });
+    res
+}
+
+fn skip_variant(
+    name: &Ident,
+    display_name: &TokenStream,
+    fields: &Fields,
+) -> Result<TokenStream, syn::Error> {
+    match fields {
+        Fields::Named(_) => {
+            Ok(quote! { Self::#name{..} => f.debug_struct(#display_name).finish(), })
+        }
+        Fields::Unnamed(_) => {
+            Ok(quote! { Self::#name(..) => f.debug_tuple(#display_name).finish(), })
+        }
+        Fields::Unit => Ok(quote! { Self::#name => f.write_str(#display_name), }),
+    }
+}
+
+/// Generates a match arm that prints nothing at all for the given variant.
+fn silent_variant(name: &Ident, fields: &Fields) -> TokenStream {
+    match fields {
+        Fields::Named(_) => quote! { Self::#name{..} => Ok(()), },
+        Fields::Unnamed(_) => quote! { Self::#name(..) => Ok(()), },
+        Fields::Unit => quote! { Self::#name => Ok(()), },
+    }
+}
+
+/// Generates a match arm that prints the single field of the given variant instead of the variant itself.
+fn catch_all_variant(
+    variant: &Variant,
+    container: &FieldOutputOptions,
+) -> Result<TokenStream, syn::Error> {
+    if variant.fields.len() != 1 {
+        return Err(syn::Error::new_spanned(
+            variant,
+            "#[dbg(catch_all)] requires the variant to have exactly one field",
+        ));
+    }
+
+    let name = &variant.ident;
+    let match_list = derive_match_list(&variant.fields, container)?;
+    let fields = match &variant.fields {
+        Fields::Named(fields) => derive_named_fields(fields, false, container)?,
+        Fields::Unnamed(fields) => derive_unnamed_fields(fields, false, container)?,
+        Fields::Unit => unreachable!(),
+    };
+
+    match fields.first() {
+        Some(FieldEntry { expand: true, .. }) => Err(syn::Error::new_spanned(
+            variant,
+            "#[dbg(catch_all)] does not support expand_with",
+        )),
+        Some(FieldEntry { value, .. }) => Ok(quote! {
+            Self::#name #match_list => __dbg_fmt::Debug::fmt(#value, f),
+        }),
+        None => Ok(quote! { Self::#name #match_list => Ok(()), }),
+    }
+}
+
+/// Generates the pattern binding the fields of a variant.
+///
+/// Fields whose value is not used by the generated output are bound to `_`, so that they don't cause
+/// unused variable warnings. This has to match the fields accessed by [`derive_field_value`].
+fn derive_match_list(
+    fields: &Fields,
+    container: &FieldOutputOptions,
+) -> Result<TokenStream, syn::Error> {
+    // unused fields still have to be bound if another field uses them as its precision or format argument
+    let mut referenced = Vec::new();
+    for field in fields {
+        let target = match field.ident {
+            Some(_) => OptionsTarget::NamedField,
+            None => OptionsTarget::UnnamedField,
+        };
+        let options = parse_options(&field.attrs, target)?;
+        if let FieldPrintType::FormatPrecision(_, member) = options.print_type {
+            referenced.push(member);
+        }
+        referenced.extend(
+            options
+                .fmt_args
+                .iter()
+                .filter_map(fmt_args::field_name)
+                .map(Member::Named),
+        );
+    }
+
+    match fields {
+        Fields::Named(fields) => {
+            let mut res = TokenStream::new();
+            for field in &fields.named {
+                let name = field.ident.as_ref().unwrap();
+                let options = parse_options(&field.attrs, OptionsTarget::NamedField)?;
+
+                if uses_field_value(&options, container)
+                    || referenced.contains(&Member::Named(name.clone()))
+                {
+                    let binding = derive_field_ref(&Member::Named(name.clone()), false);
+                    res.extend(quote! { #name: #binding, })
+                } else {
+                    res.extend(quote! { #name: _, })
+                }
+            }
+            Ok(quote! { { #res } })
+        }
+        Fields::Unnamed(fields) => {
+            let mut res = TokenStream::new();
+            for (i, field) in fields.unnamed.iter().enumerate() {
+                let name = format_ident!("field_{}", i);
+                let options = parse_options(&field.attrs, OptionsTarget::UnnamedField)?;
+
+                if uses_field_value(&options, container)
+                    || referenced.contains(&Member::Unnamed(syn::Index::from(i)))
+                {
+                    res.extend(quote! { #name, })
+                } else {
+                    res.extend(quote! { _, })
+                }
+            }
+            Ok(quote! { (#res) })
+        }
+        Fields::Unit => Ok(quote! {}),
+    }
+}
+
+fn derive_named_fields(
+    fields: &FieldsNamed,
+    use_self: bool,
+    container: &FieldOutputOptions,
+) -> Result<Vec<FieldEntry>, syn::Error> {
+    let mut res = Vec::new();
+
+    for (i, field) in fields.named.iter().enumerate() {
+        let name = field.ident.as_ref().unwrap();
+
+        let options = parse_field_options(field, OptionsTarget::NamedField, container)?;
+
+        let name_str = if let Some(alias) = &options.alias {
+            quote! { #alias }
+        } else {
+            derive_name(None, &name.unraw().to_string(), container)
+        };
+        let name_str = derive_translated_name(name_str, container);
+
+        let name_str = if container.color {
+            derive_colored_name(name_str)
+        } else {
+            name_str
+        };
+
+        let field_ref = derive_field_ref(&Member::Named(name.clone()), use_self);
+
+        if let Some(value) =
+            derive_field_value(&options, container, &field.ty, &field_ref, use_self)
+        {
+            // expanded fields replace the field's own name and value by the returned pairs
+            let (value, expand) = match &options.expand_with {
+                Some(expand_with) => (quote! { #expand_with(#field_ref) }, true),
+                None => (value, false),
+            };
+            let entry = FieldEntry {
+                name: Some(name_str),
+                value,
+                condition: derive_condition(&options, container, &field_ref)?,
+                expand,
+            };
+            // explicitly positioned fields come before fields declared at the same index
+            let position = (options.position.unwrap_or(i), options.position.is_none());
+            res.push((position, entry));
+        }
+    }
+
+    // only the printed entries are reordered, the bindings of enum variants stay in declaration order
+    res.sort_by_key(|(position, _)| *position);
+
+    Ok(res.into_iter().map(|(_, entry)| entry).collect())
+}
+
+fn derive_unnamed_fields(
+    fields: &FieldsUnnamed,
+    use_self: bool,
+    container: &FieldOutputOptions,
+) -> Result<Vec<FieldEntry>, syn::Error> {
+    let mut res = Vec::new();
+
+    let options = fields
+        .unnamed
+        .iter()
+        .map(|field| parse_field_options(field, OptionsTarget::UnnamedField, container))
+        .collect::<Result<Vec<_>, _>>()?;
+    let has_alias = options.iter().any(|options| options.alias.is_some());
+
+    for (i, (field, options)) in fields.unnamed.iter().zip(options).enumerate() {
+        // if any field has an alias or `with_index` or `logfmt` is set, every field is printed with a name,
+        // generated from its index by default. Only generated names are affected by `rename_all`.
+        let name = (has_alias || container.with_index || container.logfmt).then(|| {
+            let name = match &options.alias {
+                Some(alias) => quote! { #alias },
+                None if container.with_index => {
+                    derive_name(None, &format!("field{}", i), container)
+                }
+                None => derive_name(None, &i.to_string(), container),
+            };
+            let name = derive_translated_name(name, container);
+            if container.color {
+                derive_colored_name(name)
+            } else {
+                name
+            }
+        });
+
+        let field_ref = derive_field_ref(&Member::Unnamed(syn::Index::from(i)), use_self);
+
+        if let Some(value) =
+            derive_field_value(&options, container, &field.ty, &field_ref, use_self)
+        {
+            res.push(FieldEntry {
+                name,
+                value,
+                condition: derive_condition(&options, container, &field_ref)?,
+                expand: false,
+            });
+        }
+    }
+
+    Ok(res)
+}
+
+/// Parses the options of a field that is printed.
+///
+/// Fields whose type is one of the `skip_bound` parameters of the container are printed like `opaque`,
+/// unless they specify how they are printed themselves.
+fn parse_field_options(
+    field: &Field,
+    target: OptionsTarget,
+    container: &FieldOutputOptions,
+) -> Result<FieldOutputOptions, syn::Error> {
+    let mut options = parse_options(&field.attrs, target)?;
+
+    check_std_only_options(&options, &container.crate_path, field)?;
+
+    // the default placeholder is printed instead of the value, so the value must not be expanded either
+    if let (Some(expand_with), FieldPrintType::Normal, Some(_)) = (
+        &options.expand_with,
+        &options.print_type,
+        &container.default_placeholder,
+    ) {
+        return Err(syn::Error::new_spanned(
+            expand_with,
+            "expand_with can not be combined with a default placeholder",
+        ));
+    }
+
+    if let (FieldPrintType::Normal, Type::Path(ty)) = (&options.print_type, &field.ty) {
+        if ty.qself.is_none()
+            && container
+                .skip_bound
+                .iter()
+                .any(|param| ty.path.is_ident(param))
+        {
+            options.print_type = FieldPrintType::Opaque;
+        }
+    }
+
+    Ok(options)
+}
+
+/// Returns the options that need the standard library, together with whether `options` uses them.
+///
+/// The README lists the same options in the documentation of `crate_path`.
+fn std_only_options(options: &FieldOutputOptions) -> [(&'static str, bool); 10] {
+    [
+        ("color", options.color),
+        ("detect_cycles", options.detect_cycles),
+        ("fit_width", options.fit_width),
+        ("logfmt", options.logfmt),
+        ("dedup_fields", options.dedup_fields),
+        ("rename_all_with", options.rename_all_with.is_some()),
+        ("cache_format", options.cache_format),
+        (
+            "cow_tag",
+            matches!(options.print_type, FieldPrintType::CowTag),
+        ),
+        ("ip", matches!(options.print_type, FieldPrintType::Ipv4)),
+        (
+            "backtrace",
+            matches!(options.print_type, FieldPrintType::Backtrace),
+        ),
+    ]
+}
+
+/// Returns an error pointing at `tokens` if `options` use an option that needs the standard library
+/// while `crate_path` is `core`.
+fn check_std_only_options(
+    options: &FieldOutputOptions,
+    crate_path: &Ident,
+    tokens: impl ToTokens,
+) -> Result<(), syn::Error> {
+    if crate_path != "core" {
+        return Ok(());
+    }
+    match std_only_options(options)
+        .into_iter()
+        .find_map(|(option, used)| used.then_some(option))
+    {
+        Some(option) => Err(syn::Error::new_spanned(
+            tokens,
+            format!(
+                "{} requires std and can not be used with crate_path = \"core\"",
+                option
+            ),
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Returns the style tuple fields generated by [`derive_unnamed_fields`] are printed in,
+/// which is [`FieldsStyle::Named`] if any of them has an alias.
+fn unnamed_fields_style(fields: &[FieldEntry]) -> FieldsStyle {
+    if fields.iter().any(|field| field.name.is_some()) {
+        FieldsStyle::Named
+    } else {
+        FieldsStyle::Unnamed
+    }
+}
+
+/// Generates the condition deciding whether the field referenced by `field_ref` is printed.
+///
+/// A field with a `level` is only printed if the `verbosity` function of the container returns at least that level.
+fn derive_condition(
+    options: &FieldOutputOptions,
+    container: &FieldOutputOptions,
+    field_ref: &TokenStream,
+) -> Result<Option<TokenStream>, syn::Error> {
+    let mut conditions = Vec::new();
+
+    if let Some(level) = &options.level {
+        let verbosity = container.verbosity.as_ref().ok_or_else(|| {
+            syn::Error::new_spanned(
+                level,
+                "level requires the verbosity option on the container",
+            )
+        })?;
+        conditions.push(quote! { #verbosity() >= #level });
+    }
+    if let Some(skip_if) = &options.skip_if {
+        conditions.push(quote! { !#skip_if(#field_ref) });
+    }
+    if options.skip_if_none {
+        conditions.push(quote! { ::core::option::Option::is_some(#field_ref) });
+    }
+
+    if conditions.is_empty() {
+        Ok(None)
+    } else {
+        Ok(Some(quote! { #(#conditions)&&* }))
+    }
+}
+
+/// Returns whether the field is hidden because the container uses `opt_in` and the field is not marked with `show`.
+fn is_hidden(options: &FieldOutputOptions, container: &FieldOutputOptions) -> bool {
+    container.opt_in && !options.show
+}
+
+/// Returns whether any of `fields` is skipped or hidden by `opt_in`, in which case the output is marked as non-exhaustive.
+///
+/// Fields omitted at runtime, e.g. by `skip_if`, are not taken into account.
+fn hides_fields(fields: &Fields, container: &FieldOutputOptions) -> Result<bool, syn::Error> {
+    for field in fields {
+        let target = match field.ident {
+            Some(_) => OptionsTarget::NamedField,
+            None => OptionsTarget::UnnamedField,
+        };
+        let options = parse_options(&field.attrs, target)?;
+        if is_hidden(&options, container) || matches!(options.print_type, FieldPrintType::Skip) {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+/// Generates the arguments passed to the format string of the `fmt` option.
+///
+/// These are either the field itself or the arguments specified after the format string,
+/// where bare identifiers are replaced by references to the fields of the same name.
+fn derive_fmt_args(
+    options: &FieldOutputOptions,
+    field_ref: &TokenStream,
+    use_self: bool,
+) -> Vec<TokenStream> {
+    if options.fmt_args.is_empty() {
+        return vec![field_ref.clone()];
+    }
+
+    options
+        .fmt_args
+        .iter()
+        .map(|arg| match fmt_args::field_name(arg) {
+            Some(name) => derive_field_ref(&Member::Named(name), use_self),
+            None => arg.clone(),
+        })
+        .collect()
+}
+
+/// Returns whether the output generated by [`derive_field_value`] accesses the value of the field.
+fn uses_field_value(options: &FieldOutputOptions, container: &FieldOutputOptions) -> bool {
+    match options.print_type {
+        FieldPrintType::Skip => false,
+        _ if is_hidden(options, container) => false,
+        _ if options.skip_if.is_some() || options.skip_if_none => true,
+        // the cache is keyed by the hash of the field, even if its value is not printed
+        _ if options.cache_format => true,
+        _ if options.expand_with.is_some() => true,
+        FieldPrintType::Placeholder(_) | FieldPrintType::Redact(_) => options.raw_and.is_some(),
+        FieldPrintType::Normal if container.default_placeholder.is_some() => {
+            options.raw_and.is_some()
+        }
+        // fields referenced by format arguments are bound by `derive_match_list` instead
+        FieldPrintType::Format(_) | FieldPrintType::FormatPrecision(..)
+            if !options.fmt_args.is_empty() =>
+        {
+            options.raw_and.is_some()
+        }
+        _ => true,
+    }
+}
+
+/// Generates an expression evaluating to a reference to the given field.
+///
+/// If `use_self` is not set, the field is referenced by the name bound in [`derive_match_list`],
+/// which is prefixed so that a field called e.g. `f` does not shadow the formatter.
+fn derive_field_ref(member: &Member, use_self: bool) -> TokenStream {
+    match (member, use_self) {
+        (member, true) => quote! { &self.#member },
+        (Member::Named(name), false) => format_ident!("field_{}", name).to_token_stream(),
+        (Member::Unnamed(index), false) => format_ident!("field_{}", index.index).to_token_stream(),
+    }
+}
+
+/// Generates an expression evaluating to a reference to something implementing [`Debug`](std::fmt::Debug)
+/// that represents the field referenced by `field_ref`.
+///
+/// `field_ref` has to be an expression of type `&T`, where `T` is the type of the field.
+/// Returns `None` if the field should not be printed at all.
+fn derive_field_value(
+    options: &FieldOutputOptions,
+    container: &FieldOutputOptions,
+    ty: &Type,
+    field_ref: &TokenStream,
+    use_self: bool,
+) -> Option<TokenStream> {
+    if is_hidden(options, container) {
+        return None;
+    }
+
+    // the value is only evaluated if the field is `Some`, see `derive_condition`
+    let field_ref = &if options.skip_if_none {
+        quote! { ::core::option::Option::as_ref(#field_ref).unwrap() }
+    } else {
+        field_ref.clone()
+    };
+
+    let field_ref = &if options.access.is_empty() {
+        field_ref.clone()
+    } else {
+        let access = &options.access;
+        quote! { &(#field_ref)#(.#access)* }
+    };
+
+    // the target is referenced twice, as it may be unsized like `str`
+    let field_ref = &if options.deref {
+        quote! { &&**#field_ref }
+    } else {
+        field_ref.clone()
+    };
+
+    let field_ref = &match (&options.mask, &options.shift) {
+        (Some(mask), Some(shift)) => quote! { &((*#field_ref & #mask) >> #shift) },
+        (Some(mask), None) => quote! { &(*#field_ref & #mask) },
+        (None, Some(shift)) => quote! { &(*#field_ref >> #shift) },
+        (None, None) => field_ref.clone(),
+    };
+
+    let value = match (&options.print_type, &container.default_placeholder) {
+        (FieldPrintType::Normal, Some(placeholder)) => quote! { &format_args!(#placeholder) },
+        (print_type, _) => {
+            let fmt_args = derive_fmt_args(options, field_ref, use_self);
+            derive_print_type(print_type, container, field_ref, &fmt_args, use_self)?
+        }
+    };
+
+    let value = if let Some(unit) = &options.unit {
+        quote! {
+            &DbgFn(|f: &mut __dbg_fmt::Formatter<'_>| {
+                __dbg_fmt::Debug::fmt(#value, f)?;
+                f.write_str(#unit)
+            })
+        }
+    } else {
+        value
+    };
+
+    let value = if let Some(decode) = &options.raw_and {
+        quote! {
+            &DbgFn(|f: &mut __dbg_fmt::Formatter<'_>| {
+                __dbg_fmt::Debug::fmt(#value, f)?;
+                write!(f, " ({})", #decode(#field_ref))
+            })
+        }
+    } else {
+        value
+    };
+
+    let value = if container.show_types {
+        quote! {
+            &DbgFn(|f: &mut __dbg_fmt::Formatter<'_>| {
+                __dbg_fmt::Debug::fmt(#value, f)?;
+                write!(f, " ({})", ::core::any::type_name::<#ty>())
+            })
+        }
+    } else {
+        value
+    };
+
+    let value = if let Some(label) = &options.label {
+        let label = format!("[{}] ", label);
+        quote! {
+            &DbgFn(|f: &mut __dbg_fmt::Formatter<'_>| {
+                f.write_str(#label)?;
+                __dbg_fmt::Debug::fmt(#value, f)
+            })
+        }
+    } else {
+        value
+    };
+
+    let value = if options.cache_format {
+        derive_cached_value(&value, field_ref)
+    } else {
+        value
+    };
+
+    Some(value)
+}
+
+/// Parses the `.` separated field names and indices of the `access` option, e.g. `"0.name"`.
+fn parse_access(access: &LitStr) -> Result<Vec<Member>, syn::Error> {
+    access
+        .value()
+        .split('.')
+        .map(|member| {
+            syn::parse_str::<Member>(member.trim()).map_err(|_| {
+                syn::Error::new_spanned(
+                    access,
+                    "access must consist of field names and indices separated by `.`",
+                )
+            })
+        })
+        .collect()
+}
+
+/// Maximum number of outputs cached per field and thread before the cache is cleared.
+const FORMAT_CACHE_SIZE: usize = 256;
+
+/// Wraps `value` so that its output is cached per thread, keyed by the hash of the field and the `#` flag.
+/// The cache is bypassed if any other formatting flag, e.g. a width, is set.
+///
+/// The cache is a `static` and therefore shared by all instantiations of a generic type,
+/// so the type name is hashed as well.
+fn derive_cached_value(value: &TokenStream, field_ref: &TokenStream) -> TokenStream {
+    quote! {
+        &DbgFn(|f: &mut __dbg_fmt::Formatter<'_>| {
+            ::std::thread_local! {
+                static CACHE: ::std::cell::RefCell<
+                    ::std::collections::HashMap<(u64, bool), ::std::string::String>,
+                > = ::std::cell::RefCell::new(::std::collections::HashMap::new());
+            }
+
+            if f.width().is_some()
+                || f.precision().is_some()
+                || f.align().is_some()
+                || f.fill() != ' '
+                || f.sign_plus()
+                || f.sign_minus()
+                || f.sign_aware_zero_pad()
+            {
+                return __dbg_fmt::Debug::fmt(#value, f);
+            }
+
+            let mut hasher = ::std::collections::hash_map::DefaultHasher::new();
+            ::std::hash::Hash::hash(::std::any::type_name::<Self>(), &mut hasher);
+            ::std::hash::Hash::hash(#field_ref, &mut hasher);
+            let key = (::std::hash::Hasher::finish(&hasher), f.alternate());
+
+            CACHE.with(|cache| {
+                if let Some(formatted) = cache.borrow().get(&key) {
+                    return f.write_str(formatted);
+                }
+
+                // no borrow is held while formatting, as the value may contain the same type again
+                let formatted = if key.1 {
+                    ::std::format!("{:#?}", #value)
+                } else {
+                    ::std::format!("{:?}", #value)
+                };
+                let mut cache = cache.borrow_mut();
+                if cache.len() >= #FORMAT_CACHE_SIZE {
+                    cache.clear();
+                }
+                f.write_str(cache.entry(key).or_insert(formatted))
+            })
+        })
+    }
+}
+
+fn derive_print_type(
+    print_type: &FieldPrintType,
+    container: &FieldOutputOptions,
+    field_ref: &TokenStream,
+    fmt_args: &[TokenStream],
+    use_self: bool,
+) -> Option<TokenStream> {
+    match print_type {
+        FieldPrintType::Normal => Some(quote! { #field_ref }),
+        FieldPrintType::Placeholder(placeholder) => Some(quote! { &format_args!(#placeholder) }),
+        FieldPrintType::Redact(mask) => Some(quote! { &format_args!("{}", #mask) }),
+        // nothing is revealed if the hidden part would be shorter than the revealed parts
+        FieldPrintType::RedactPartial(reveal) => Some(quote! {
+            &DbgFn(|f: &mut __dbg_fmt::Formatter<'_>| {
+                let value: &str = ::core::convert::AsRef::<str>::as_ref(#field_ref);
+                let count = value.chars().count();
+                if count <= 2 * #reveal {
+                    return f.write_str("\u{2026}");
+                }
+                let index = |n: usize| value.char_indices().nth(n).map_or(value.len(), |(i, _)| i);
+                f.write_str(&value[..index(#reveal)])?;
+                f.write_str("\u{2026}")?;
+                f.write_str(&value[index(count - #reveal)..])
+            })
+        }),
+        FieldPrintType::Format(fmt) => Some(quote! { &format_args!(#fmt, #(#fmt_args),*) }),
+        FieldPrintType::FormatPrecision(fmt, precision) => {
+            let precision = derive_field_ref(precision, use_self);
+            Some(quote! { &format_args!(#fmt, *#precision as usize, #(#fmt_args),*) })
+        }
+        // the formatter calls use the span of the attribute, so that errors caused by a wrong
+        // signature point at the attribute instead of the derive
+        FieldPrintType::Custom(formatter) => {
+            let call = quote_spanned! { formatter.span()=> #formatter(#field_ref) };
+            Some(quote! { &format_args!("{}", #call) })
+        }
+        FieldPrintType::CustomAlt(formatter, alt_formatter) => {
+            let call = quote_spanned! { formatter.span()=> #formatter(#field_ref) };
+            let alt_call = quote_spanned! { alt_formatter.span()=> #alt_formatter(#field_ref) };
+            Some(quote! {
+                &DbgFn(|f: &mut __dbg_fmt::Formatter<'_>| {
+                    if f.alternate() {
+                        write!(f, "{}", #alt_call)
+                    } else {
+                        write!(f, "{}", #call)
+                    }
+                })
+            })
+        }
+        FieldPrintType::CustomOwned(formatter) => {
+            let call = quote_spanned! { formatter.span()=> #formatter(*#field_ref) };
+            Some(quote! { &format_args!("{}", #call) })
+        }
+        FieldPrintType::CustomEach(formatter) => {
+            let item = quote! { item };
+            let call = quote_spanned! { formatter.span()=> #formatter(#item) };
+            Some(quote! {
+                &DbgFn(|f: &mut __dbg_fmt::Formatter<'_>| {
+                    let mut list = f.debug_list();
+                    for item in ::core::iter::IntoIterator::into_iter(#field_ref) {
+                        list.entry(&format_args!("{}", #call));
+                    }
+                    list.finish()
+                })
+            })
+        }
+        FieldPrintType::DebugWith(formatter) => {
+            let call = quote_spanned! { formatter.span()=> #formatter(#field_ref) };
+            Some(quote! { &#call })
+        }
+        FieldPrintType::Via(ty) => Some(quote! {
+            &DbgFn(|f: &mut __dbg_fmt::Formatter<'_>| <#ty>::fmt(#field_ref, f))
+        }),
+        FieldPrintType::Lookup(table) => Some(quote! {
+            &DbgFn(|f: &mut __dbg_fmt::Formatter<'_>| match #table.get(*#field_ref as usize) {
+                Some(name) => __dbg_fmt::Display::fmt(name, f),
+                None => f.write_str("<out of range>"),
+            })
+        }),
+        FieldPrintType::TupleField(index) => Some(quote! { &(#field_ref).#index }),
+        FieldPrintType::CollectAs(ty) => {
+            Some(quote! { &(#field_ref).iter().cloned().collect::<#ty>() })
+        }
+        #[cfg(feature = "serde")]
+        FieldPrintType::JsonCompact => Some(quote! {
+            &DbgFn(|f: &mut __dbg_fmt::Formatter<'_>| match ::serde_json::to_string(#field_ref) {
+                Ok(json) => f.write_str(&json),
+                Err(_) => f.write_str("<serialization error>"),
+            })
+        }),
+        FieldPrintType::Rle => Some(quote! {
+            &DbgFn(|f: &mut __dbg_fmt::Formatter<'_>| {
+                let mut list = f.debug_list();
+                let mut iter = ::core::iter::IntoIterator::into_iter(#field_ref).peekable();
+                while let Some(item) = iter.next() {
+                    let mut count = 1usize;
+                    while iter.next_if(|next| *next == item).is_some() {
+                        count += 1;
+                    }
+                    if count > 1 {
+                        list.entry(&format_args!("{:?} × {}", item, count));
+                    } else {
+                        list.entry(&item);
+                    }
+                }
+                list.finish()
+            })
+        }),
+        FieldPrintType::Collection => match container.max_elements {
+            Some(max) => Some(quote! {
+                &DbgFn(|f: &mut __dbg_fmt::Formatter<'_>| {
+                    let mut list = f.debug_list();
+                    let mut iter = ::core::iter::IntoIterator::into_iter(#field_ref);
+                    list.entries((&mut iter).take(#max));
+                    if iter.next().is_some() {
+                        list.finish_non_exhaustive()
+                    } else {
+                        list.finish()
+                    }
+                })
+            }),
+            None => Some(quote! { #field_ref }),
+        },
+        FieldPrintType::ByteLen => Some(quote! {
+            &format_args!(
+                "<{} bytes>",
+                ::core::convert::AsRef::<[u8]>::as_ref(#field_ref).len()
+            )
+        }),
+        FieldPrintType::Bytes(false) => Some(quote! {
+            &DbgFn(|f: &mut __dbg_fmt::Formatter<'_>| {
+                for byte in ::core::convert::AsRef::<[u8]>::as_ref(#field_ref) {
+                    write!(f, "{:02x}", byte)?;
+                }
+                Ok(())
+            })
+        }),
+        // rows of 16 bytes, which are printed on separate lines prefixed with their offset for `{:#?}`
+        FieldPrintType::Bytes(true) => Some(quote! {
+            &DbgFn(|f: &mut __dbg_fmt::Formatter<'_>| {
+                let bytes = ::core::convert::AsRef::<[u8]>::as_ref(#field_ref);
+                for (row, chunk) in bytes.chunks(16).enumerate() {
+                    match row {
+                        0 => {}
+                        _ if f.alternate() => f.write_str("\n")?,
+                        _ => f.write_str("  ")?,
+                    }
+                    if f.alternate() {
+                        write!(f, "{:08x}:", row * 16)?;
+                    }
+                    for (i, byte) in chunk.iter().enumerate() {
+                        match i {
+                            0 if !f.alternate() => {}
+                            8 => f.write_str("  ")?,
+                            _ => f.write_str(" ")?,
+                        }
+                        write!(f, "{:02x}", byte)?;
+                    }
+                }
+                Ok(())
+            })
+        }),
+        FieldPrintType::With(path, args) => Some(quote! {
+            &DbgFn(|f: &mut __dbg_fmt::Formatter<'_>| #path(#field_ref, #(#args,)* f))
+        }),
+        FieldPrintType::EnumIndex => Some(quote! { &format_args!("{}", *#field_ref as i64) }),
+        FieldPrintType::CustomOpt(formatter) => Some(quote! {
+            &DbgFn(|f: &mut __dbg_fmt::Formatter<'_>| match #formatter(#field_ref) {
+                Some(formatted) => __dbg_fmt::Display::fmt(&formatted, f),
+                None => __dbg_fmt::Debug::fmt(#field_ref, f),
+            })
+        }),
+        FieldPrintType::Len(template) => {
+            Some(quote! { &format_args!(#template, (#field_ref).len()) })
+        }
+        FieldPrintType::OptionFormatter(formatter, none) => Some(quote! {
+            &DbgFn(|f: &mut __dbg_fmt::Formatter<'_>| match #field_ref {
+                Some(value) => __dbg_fmt::Display::fmt(&#formatter(value), f),
+                None => f.write_str(#none),
+            })
+        }),
+        // the field is still referenced so it does not count as never read
+        FieldPrintType::Opaque => Some(quote! {
+            &DbgFn(|f: &mut __dbg_fmt::Formatter<'_>| {
+                let _ = #field_ref;
+                f.write_str("<opaque>")
+            })
+        }),
+        FieldPrintType::CowTag => Some(quote! {
+            &DbgFn(|f: &mut __dbg_fmt::Formatter<'_>| match #field_ref {
+                ::std::borrow::Cow::Borrowed(value) => f.debug_tuple("Borrowed").field(value).finish(),
+                ::std::borrow::Cow::Owned(value) => f.debug_tuple("Owned").field(value).finish(),
+            })
+        }),
+        FieldPrintType::Ipv4 => Some(quote! {
+            &format_args!("{}", ::std::net::Ipv4Addr::from(*#field_ref))
+        }),
+        FieldPrintType::Mac => Some(quote! {
+            &DbgFn(|f: &mut __dbg_fmt::Formatter<'_>| {
+                let mac: &[u8; 6] = #field_ref;
+                write!(
+                    f,
+                    "{:02x}:{:02x}:{:02x}:{:02x}:{:02x}:{:02x}",
+                    mac[0], mac[1], mac[2], mac[3], mac[4], mac[5]
+                )
+            })
+        }),
+        FieldPrintType::FnPtr => Some(quote! { &format_args!("{:p}", *#field_ref as *const ()) }),
+        FieldPrintType::Backtrace => Some(quote! {
+            &format_args!("{}", match (#field_ref).status() {
+                ::std::backtrace::BacktraceStatus::Captured => "<backtrace captured>",
+                ::std::backtrace::BacktraceStatus::Disabled => "<disabled>",
+                _ => "<unsupported>",
+            })
+        }),
+        FieldPrintType::Skip => None,
+    }
+}
+
+enum FieldPrintType {
+    Normal,
+    Placeholder(String),
+    /// Mask printed instead of a sensitive value.
+    Redact(String),
+    /// Number of characters revealed at the start and end of a string.
+    RedactPartial(usize),
+    Skip,
+    Format(LitStr),
+    /// Format string containing a `.*` precision and the field containing the precision.
+    FormatPrecision(LitStr, Member),
+    Custom(Path),
+    /// Formatter used for `{:?}` and formatter used for `{:#?}`.
+    CustomAlt(Path, Path),
+    CustomOwned(Path),
+    /// Formatter applied to every item of the field.
+    CustomEach(Path),
+    DebugWith(Path),
+    Via(Type),
+    Lookup(Path),
+    TupleField(syn::Index),
+    Backtrace,
+    CollectAs(Type),
+    FnPtr,
+    Rle,
+    ByteLen,
+    /// Hex dump of the bytes, spaced and split into rows if `true`.
+    Bytes(bool),
+    With(Path, Vec<Lit>),
+    EnumIndex,
+    CustomOpt(Path),
+    /// Formatter for the value of an `Option` field and the string printed for `None`.
+    OptionFormatter(Path, String),
+    Opaque,
+    CowTag,
+    Ipv4,
+    Mac,
+    Collection,
+    /// Template the number of elements is inserted into.
+    Len(LitStr),
+    #[cfg(feature = "serde")]
+    JsonCompact,
+}
+
+#[derive(Clone, Copy)]
+enum VariantStyle {
+    Short,
+    Qualified,
+}
+
+struct FieldOutputOptions {
+    print_type: FieldPrintType,
+    alias: Option<String>,
+    alias_prefix: Option<String>,
+    alias_suffix: Option<String>,
+    name_concat: Option<NameConcat>,
+    label: Option<String>,
+    variant_style: Option<VariantStyle>,
+    inline: bool,
+    transparent: bool,
+    /// Prints the discriminant of unit variants after their name.
+    discriminant: bool,
+    /// The primitive integer type given in `#[repr(...)]`.
+    repr: Option<Ident>,
+    /// Prints the fields of tuple structs and variants like a struct, named `field0`, `field1`, ...
+    with_index: bool,
+    opt_in: bool,
+    show: bool,
+    verbosity: Option<Path>,
+    level: Option<LitInt>,
+    version: Option<LitStr>,
+    version_field: Option<LitStr>,
+    /// Name of the trailing field containing the size of the type.
+    show_size: Option<LitStr>,
+    best_effort: bool,
+    rename_all: Option<RenameRule>,
+    rename_all_with: Option<Path>,
+    /// Function translating every displayed name.
+    i18n: Option<Path>,
+    show_types: bool,
+    silent: bool,
+    crate_prefixed: bool,
+    catch_all: bool,
+    non_exhaustive: bool,
+    skip_if: Option<Path>,
+    skip_if_none: bool,
+    /// Prints the target of the field's `Deref` implementation instead of the field.
+    deref: bool,
+    /// Members accessed on the field, the result is printed instead of the field.
+    access: Vec<Member>,
+    cache_format: bool,
+    /// Arguments passed to the format string of `fmt` instead of the field.
+    fmt_args: Vec<TokenStream>,
+    /// Function returning the `(name, value)` pairs a field is printed as.
+    expand_with: Option<Path>,
+    color: bool,
+    max_elements: Option<usize>,
+    names_from: Option<Path>,
+    raw_and: Option<Path>,
+    order: Option<LitStr>,
+    /// Sort key of a named field, fields without one use their index.
+    position: Option<usize>,
+    detect_cycles: bool,
+    mask: Option<LitInt>,
+    shift: Option<LitInt>,
+    logfmt: bool,
+    dedup_fields: bool,
+    /// Prints only the name of the type if `debug_assertions` are disabled.
+    minimal_in_release: bool,
+    default_placeholder: Option<String>,
+    unit: Option<String>,
+    fit_width: bool,
+    union_field: Option<LitStr>,
+    non_exhaustive_if: Option<Expr>,
+    groups: Vec<FieldGroup>,
+    /// Additional predicates for the where clause of the generated impl.
+    bounds: Vec<WherePredicate>,
+    /// Type parameters whose fields are printed like `opaque`, so that they don't need to implement `Debug`.
+    skip_bound: Vec<Ident>,
+    /// `std` or `core`, the crate the `fmt` module is taken from.
+    crate_path: Ident,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum OptionsTarget {
+    DeriveItem,
+    EnumVariant,
+    NamedField,
+    UnnamedField,
+}
+
+fn parse_options(
+    attributes: &[Attribute],
+    target: OptionsTarget,
+) -> Result<FieldOutputOptions, syn::Error> {
+    let mut res = FieldOutputOptions {
+        print_type: FieldPrintType::Normal,
+        alias: None,
+        alias_prefix: None,
+        alias_suffix: None,
+        name_concat: None,
+        label: None,
+        variant_style: None,
+        inline: false,
+        transparent: false,
+        discriminant: false,
+        repr: None,
+        with_index: false,
+        opt_in: false,
+        show: false,
+        verbosity: None,
+        level: None,
+        version: None,
+        version_field: None,
+        show_size: None,
+        best_effort: false,
+        rename_all: None,
+        rename_all_with: None,
+        i18n: None,
+        show_types: false,
+        silent: false,
+        crate_prefixed: false,
+        catch_all: false,
+        non_exhaustive: false,
+        skip_if: None,
+        skip_if_none: false,
+        deref: false,
+        access: Vec::new(),
+        cache_format: false,
+        fmt_args: Vec::new(),
+        expand_with: None,
+        color: false,
+        max_elements: None,
+        names_from: None,
+        raw_and: None,
+        order: None,
+        position: None,
+        detect_cycles: false,
+        mask: None,
+        shift: None,
+        logfmt: false,
+        dedup_fields: false,
+        minimal_in_release: false,
+        default_placeholder: None,
+        unit: None,
+        fit_width: false,
+        union_field: None,
+        non_exhaustive_if: None,
+        groups: Vec::new(),
+        bounds: Vec::new(),
+        skip_bound: Vec::new(),
+        crate_path: format_ident!("std"),
+    };
+    let mut with_args = None;
+    let mut none = None;
+    let mut alt_formatter = None;
+    let mut deref = None;
+    let mut cache_format = None;
+    let mut precision_from = None;
+    let mut rename_all = None;
+    let mut radix = None;
+    let mut display = None;
+
+    // presets are applied first, so that the other options can override them
+    if target == OptionsTarget::DeriveItem {
+        if let Some(preset) = find_preset(attributes)? {
+            apply_preset(&mut res, &preset)?;
+        }
+        res.groups = group::find_groups(attributes)?;
+        res.repr = find_repr(attributes);
+    }
+
+    for attrib in attributes {
+        if !attrib.path.is_ident("dbg")
+            || (target == OptionsTarget::DeriveItem && group::is_group_attribute(attrib))
+        {
+            continue;
+        }
+
+        let (meta, args) = fmt_args::split_fmt_args(attrib, |ident| {
+            parse_options(&[syn::parse_quote!(#[dbg(#ident)])], target).is_ok()
+        })?;
+        if !args.is_empty() {
+            res.fmt_args = args;
+        }
+
+        let meta = if let Meta::List(m) = meta {
+            m
+        } else {
+            return Err(syn::Error::new_spanned(
+                meta,
+                "invalid #[dbg(...)] attribute",
+            ));
+        };
+
+        for option in meta.nested {
+            match option {
+                NestedMeta::Meta(Meta::Path(option))
+                    if option.is_ident("skip") && target != OptionsTarget::DeriveItem =>
+                {
+                    res.print_type = FieldPrintType::Skip
+                }
+                NestedMeta::Meta(Meta::Path(option))
+                    if option.is_ident("skip_if_none")
+                        && (target == OptionsTarget::NamedField
+                            || target == OptionsTarget::UnnamedField) =>
+                {
+                    res.skip_if_none = true
+                }
+                NestedMeta::Meta(Meta::Path(option))
+                    if option.is_ident("deref")
+                        && (target == OptionsTarget::NamedField
+                            || target == OptionsTarget::UnnamedField) =>
+                {
+                    deref = Some(option)
+                }
+                NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Str(access),
+                    ..
+                })) if path.is_ident("access")
+                    && (target == OptionsTarget::NamedField
+                        || target == OptionsTarget::UnnamedField) =>
+                {
+                    res.access = parse_access(&access)?;
+                }
+                NestedMeta::Meta(Meta::Path(option))
+                    if option.is_ident("cache_format")
+                        && (target == OptionsTarget::NamedField
+                            || target == OptionsTarget::UnnamedField) =>
+                {
+                    cache_format = Some(option)
+                }
+                NestedMeta::Meta(Meta::Path(option))
+                    if option.is_ident("inline") && target == OptionsTarget::DeriveItem =>
+                {
+                    res.inline = true
+                }
+                NestedMeta::Meta(Meta::Path(option))
+                    if option.is_ident("transparent") && target == OptionsTarget::DeriveItem =>
+                {
+                    res.transparent = true
+                }
+                NestedMeta::Meta(Meta::Path(option))
+                    if option.is_ident("discriminant") && target == OptionsTarget::DeriveItem =>
+                {
+                    res.discriminant = true
+                }
+                NestedMeta::Meta(Meta::Path(option))
+                    if option.is_ident("with_index") && target == OptionsTarget::DeriveItem =>
+                {
+                    res.with_index = true
+                }
+                NestedMeta::Meta(Meta::Path(option))
+                    if option.is_ident("opt_in") && target == OptionsTarget::DeriveItem =>
+                {
+                    res.opt_in = true
+                }
+                NestedMeta::Meta(Meta::Path(option))
+                    if option.is_ident("show")
+                        && (target == OptionsTarget::NamedField
+                            || target == OptionsTarget::UnnamedField) =>
+                {
+                    res.show = true
+                }
+                #[cfg(feature = "color")]
+                NestedMeta::Meta(Meta::Path(option))
+                    if option.is_ident("color") && target == OptionsTarget::DeriveItem =>
+                {
+                    res.color = true
+                }
+                NestedMeta::Meta(Meta::Path(option))
+                    if option.is_ident("fit_width") && target == OptionsTarget::DeriveItem =>
+                {
+                    res.fit_width = true
+                }
+                NestedMeta::Meta(Meta::Path(option))
+                    if option.is_ident("logfmt") && target == OptionsTarget::DeriveItem =>
+                {
+                    res.logfmt = true
+                }
+                NestedMeta::Meta(Meta::Path(option))
+                    if option.is_ident("dedup_fields") && target == OptionsTarget::DeriveItem =>
+                {
+                    res.dedup_fields = true
+                }
+                NestedMeta::Meta(Meta::Path(option))
+                    if option.is_ident("minimal_in_release")
+                        && target == OptionsTarget::DeriveItem =>
+                {
+                    res.minimal_in_release = true
+                }
+                NestedMeta::Meta(Meta::Path(option))
+                    if option.is_ident("detect_cycles") && target == OptionsTarget::DeriveItem =>
+                {
+                    res.detect_cycles = true
+                }
+                NestedMeta::Meta(Meta::Path(option))
+                    if option.is_ident("best_effort") && target == OptionsTarget::DeriveItem =>
+                {
+                    res.best_effort = true
+                }
+                NestedMeta::Meta(Meta::Path(option))
+                    if option.is_ident("show_types") && target == OptionsTarget::DeriveItem =>
+                {
+                    res.show_types = true
+                }
+                NestedMeta::Meta(Meta::Path(option))
+                    if option.is_ident("silent") && target == OptionsTarget::EnumVariant =>
+                {
+                    res.silent = true
+                }
+                NestedMeta::Meta(Meta::Path(option))
+                    if option.is_ident("crate_prefixed") && target == OptionsTarget::DeriveItem =>
+                {
+                    res.crate_prefixed = true
+                }
+                NestedMeta::Meta(Meta::Path(option))
+                    if option.is_ident("catch_all") && target == OptionsTarget::EnumVariant =>
+                {
+                    res.catch_all = true
+                }
+                NestedMeta::Meta(Meta::Path(option))
+                    if option.is_ident("non_exhaustive")
+                        && (target == OptionsTarget::DeriveItem
+                            || target == OptionsTarget::EnumVariant) =>
+                {
+                    res.non_exhaustive = true
+                }
+                NestedMeta::Meta(Meta::Path(option))
+                    if option.is_ident("backtrace")
+                        && (target == OptionsTarget::NamedField
+                            || target == OptionsTarget::UnnamedField) =>
+                {
+                    res.print_type = FieldPrintType::Backtrace
+                }
+                #[cfg(feature = "serde")]
+                NestedMeta::Meta(Meta::Path(option))
+                    if option.is_ident("json_compact")
+                        && (target == OptionsTarget::NamedField
+                            || target == OptionsTarget::UnnamedField) =>
+                {
+                    res.print_type = FieldPrintType::JsonCompact
+                }
+                NestedMeta::Meta(Meta::Path(option))
+                    if option.is_ident("rle")
+                        && (target == OptionsTarget::NamedField
+                            || target == OptionsTarget::UnnamedField) =>
+                {
+                    res.print_type = FieldPrintType::Rle
+                }
+                NestedMeta::Meta(Meta::Path(option))
+                    if option.is_ident("byte_len")
+                        && (target == OptionsTarget::NamedField
+                            || target == OptionsTarget::UnnamedField) =>
+                {
+                    res.print_type = FieldPrintType::ByteLen
+                }
+                NestedMeta::Meta(Meta::Path(option))
+                    if option.is_ident("bytes")
+                        && (target == OptionsTarget::NamedField
+                            || target == OptionsTarget::UnnamedField) =>
+                {
+                    res.print_type = FieldPrintType::Bytes(false)
+                }
+                NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Str(style),
+                    ..
+                })) if path.is_ident("bytes")
+                    && (target == OptionsTarget::NamedField
+                        || target == OptionsTarget::UnnamedField) =>
+                {
+                    if style.value() != "pretty" {
+                        return Err(syn::Error::new_spanned(style, "expected \"pretty\""));
+                    }
+                    res.print_type = FieldPrintType::Bytes(true)
+                }
+                NestedMeta::Meta(Meta::Path(option))
+                    if option.is_ident("enum_index")
+                        && (target == OptionsTarget::NamedField
+                            || target == OptionsTarget::UnnamedField) =>
+                {
+                    res.print_type = FieldPrintType::EnumIndex
+                }
+                NestedMeta::Meta(Meta::Path(option))
+                    if option.is_ident("collection")
+                        && (target == OptionsTarget::NamedField
+                            || target == OptionsTarget::UnnamedField) =>
+                {
+                    res.print_type = FieldPrintType::Collection
+                }
+                NestedMeta::Meta(Meta::Path(option))
+                    if option.is_ident("len")
+                        && (target == OptionsTarget::NamedField
+                            || target == OptionsTarget::UnnamedField) =>
+                {
+                    res.print_type = FieldPrintType::Len(LitStr::new("{} items", option.span()))
+                }
+                NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Str(template),
+                    ..
+                })) if path.is_ident("len")
+                    && (target == OptionsTarget::NamedField
+                        || target == OptionsTarget::UnnamedField) =>
+                {
+                    res.print_type = FieldPrintType::Len(template)
+                }
+                NestedMeta::Meta(Meta::Path(option))
+                    if option.is_ident("opaque")
+                        && (target == OptionsTarget::NamedField
+                            || target == OptionsTarget::UnnamedField) =>
+                {
+                    res.print_type = FieldPrintType::Opaque
+                }
+                NestedMeta::Meta(Meta::Path(option))
+                    if option.is_ident("cow_tag")
+                        && (target == OptionsTarget::NamedField
+                            || target == OptionsTarget::UnnamedField) =>
+                {
+                    res.print_type = FieldPrintType::CowTag
+                }
+                NestedMeta::Meta(Meta::Path(option))
+                    if option.is_ident("ip")
+                        && (target == OptionsTarget::NamedField
+                            || target == OptionsTarget::UnnamedField) =>
+                {
+                    res.print_type = FieldPrintType::Ipv4
+                }
+                NestedMeta::Meta(Meta::Path(option))
+                    if option.is_ident("mac")
+                        && (target == OptionsTarget::NamedField
+                            || target == OptionsTarget::UnnamedField) =>
+                {
+                    res.print_type = FieldPrintType::Mac
+                }
+                NestedMeta::Meta(Meta::Path(option))
+                    if option.is_ident("fn_ptr")
+                        && (target == OptionsTarget::NamedField
+                            || target == OptionsTarget::UnnamedField) =>
+                {
+                    res.print_type = FieldPrintType::FnPtr
+                }
+                NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Str(placeholder),
+                    ..
+                })) if path.is_ident("placeholder")
+                    && (target == OptionsTarget::NamedField
+                        || target == OptionsTarget::UnnamedField) =>
+                {
+                    res.print_type = FieldPrintType::Placeholder(placeholder.value())
+                }
+                NestedMeta::Meta(Meta::Path(option))
+                    if option.is_ident("redact")
+                        && (target == OptionsTarget::NamedField
+                            || target == OptionsTarget::UnnamedField) =>
+                {
+                    res.print_type = FieldPrintType::Redact("***".to_owned())
+                }
+                NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Str(mask),
+                    ..
+                })) if path.is_ident("redact")
+                    && (target == OptionsTarget::NamedField
+                        || target == OptionsTarget::UnnamedField) =>
+                {
+                    res.print_type = FieldPrintType::Redact(mask.value())
+                }
+                NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Int(reveal),
+                    ..
+                })) if path.is_ident("redact_partial")
+                    && (target == OptionsTarget::NamedField
+                        || target == OptionsTarget::UnnamedField) =>
+                {
+                    res.print_type = FieldPrintType::RedactPartial(reveal.base10_parse()?)
+                }
+                NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Str(alias),
+                    ..
+                })) if path.is_ident("alias") => res.alias = Some(alias.value()),
+                NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Str(prefix),
+                    ..
+                })) if path.is_ident("alias_prefix") && target == OptionsTarget::DeriveItem => {
+                    res.alias_prefix = Some(prefix.value())
+                }
+                NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Str(suffix),
+                    ..
+                })) if path.is_ident("alias_suffix") && target == OptionsTarget::DeriveItem => {
+                    res.alias_suffix = Some(suffix.value())
+                }
+                NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Str(parts),
+                    ..
+                })) if path.is_ident("name_concat") && target == OptionsTarget::DeriveItem => {
+                    res.name_concat = Some(name_concat::parse_name_concat(parts)?)
+                }
+                NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Str(label),
+                    ..
+                })) if path.is_ident("label")
+                    && (target == OptionsTarget::NamedField
+                        || target == OptionsTarget::UnnamedField) =>
+                {
+                    res.label = Some(label.value())
+                }
+                NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Str(fmt),
+                    ..
+                })) if path.is_ident("fmt")
+                    && (target == OptionsTarget::NamedField
+                        || target == OptionsTarget::UnnamedField) =>
+                {
+                    res.print_type = FieldPrintType::Format(fmt)
+                }
+                NestedMeta::Meta(Meta::Path(option))
+                    if (option.is_ident("hex")
+                        || option.is_ident("HEX")
+                        || option.is_ident("oct")
+                        || option.is_ident("bin"))
+                        && (target == OptionsTarget::NamedField
+                            || target == OptionsTarget::UnnamedField) =>
+                {
+                    radix = Some(option)
+                }
+                NestedMeta::Meta(Meta::Path(option))
+                    if option.is_ident("display")
+                        && (target == OptionsTarget::NamedField
+                            || target == OptionsTarget::UnnamedField) =>
+                {
+                    display = Some(option)
+                }
+                NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Str(field),
+                    ..
+                })) if path.is_ident("precision_from")
+                    && (target == OptionsTarget::NamedField
+                        || target == OptionsTarget::UnnamedField) =>
+                {
+                    let member = syn::parse_str::<Member>(&field.value())
+                        .map_err(|e| syn::Error::new(field.span(), e.to_string()))?;
+                    precision_from = Some((field, member));
+                }
+                NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Str(custom),
+                    ..
+                })) if path.is_ident("formatter")
+                    && (target == OptionsTarget::NamedField
+                        || target == OptionsTarget::UnnamedField) =>
+                {
+                    res.print_type = FieldPrintType::Custom(parse_fn_path(&custom)?);
+                }
+                NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Str(custom),
+                    ..
+                })) if path.is_ident("formatter_owned")
+                    && (target == OptionsTarget::NamedField
+                        || target == OptionsTarget::UnnamedField) =>
+                {
+                    res.print_type = FieldPrintType::CustomOwned(parse_fn_path(&custom)?);
+                }
+                NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Str(custom),
+                    ..
+                })) if path.is_ident("each_formatter")
+                    && (target == OptionsTarget::NamedField
+                        || target == OptionsTarget::UnnamedField) =>
+                {
+                    res.print_type = FieldPrintType::CustomEach(parse_fn_path(&custom)?);
+                }
+                NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Str(custom),
+                    ..
+                })) if path.is_ident("debug_with")
+                    && (target == OptionsTarget::NamedField
+                        || target == OptionsTarget::UnnamedField) =>
+                {
+                    res.print_type = FieldPrintType::DebugWith(parse_fn_path(&custom)?);
+                }
+                NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Str(unit),
+                    ..
+                })) if path.is_ident("unit")
+                    && (target == OptionsTarget::NamedField
+                        || target == OptionsTarget::UnnamedField) =>
+                {
+                    res.unit = Some(unit.value());
+                }
+                NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Str(decode),
+                    ..
+                })) if path.is_ident("raw_and")
+                    && (target == OptionsTarget::NamedField
+                        || target == OptionsTarget::UnnamedField) =>
+                {
+                    let path = parse_fn_path(&decode)?;
+                    res.raw_and = Some(path);
+                }
+                NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Str(ty),
+                    ..
+                })) if path.is_ident("via")
+                    && (target == OptionsTarget::NamedField
+                        || target == OptionsTarget::UnnamedField) =>
+                {
+                    let ty = syn::parse_str::<Type>(&ty.value())
+                        .map_err(|e| syn::Error::new(ty.span(), e.to_string()))?;
+                    res.print_type = FieldPrintType::Via(ty);
+                }
+                NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Str(table),
+                    ..
+                })) if path.is_ident("lookup")
+                    && (target == OptionsTarget::NamedField
+                        || target == OptionsTarget::UnnamedField) =>
+                {
+                    let path = parse_fn_path(&table)?;
+                    res.print_type = FieldPrintType::Lookup(path);
+                }
+                NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Str(ty),
+                    ..
+                })) if path.is_ident("collect_as")
+                    && (target == OptionsTarget::NamedField
+                        || target == OptionsTarget::UnnamedField) =>
                 {
-                    res.print_type = FieldPrintType::Skip
+                    let ty = syn::parse_str::<Type>(&ty.value())
+                        .map_err(|e| syn::Error::new(ty.span(), e.to_string()))?;
+                    res.print_type = FieldPrintType::CollectAs(ty);
                 }
                 NestedMeta::Meta(Meta::NameValue(MetaNameValue {
                     path,
-                    lit: Lit::Str(placeholder),
+                    lit: Lit::Str(mask),
                     ..
-                })) if path.is_ident("placeholder")
+                })) if path.is_ident("mask")
                     && (target == OptionsTarget::NamedField
                         || target == OptionsTarget::UnnamedField) =>
                 {
-                    res.print_type = FieldPrintType::Placeholder(placeholder.value())
+                    let mask = syn::parse_str::<LitInt>(&mask.value())
+                        .map_err(|e| syn::Error::new(mask.span(), e.to_string()))?;
+                    res.mask = Some(mask);
                 }
                 NestedMeta::Meta(Meta::NameValue(MetaNameValue {
                     path,
-                    lit: Lit::Str(alias),
+                    lit: Lit::Int(mask),
                     ..
-                })) if path.is_ident("alias") && target != OptionsTarget::UnnamedField => {
-                    res.alias = Some(alias.value())
+                })) if path.is_ident("mask")
+                    && (target == OptionsTarget::NamedField
+                        || target == OptionsTarget::UnnamedField) =>
+                {
+                    res.mask = Some(mask);
                 }
                 NestedMeta::Meta(Meta::NameValue(MetaNameValue {
                     path,
-                    lit: Lit::Str(fmt),
+                    lit: Lit::Int(shift),
                     ..
-                })) if path.is_ident("fmt")
+                })) if path.is_ident("shift")
                     && (target == OptionsTarget::NamedField
                         || target == OptionsTarget::UnnamedField) =>
                 {
-                    res.print_type = FieldPrintType::Format(fmt)
+                    res.shift = Some(shift);
+                }
+                NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Int(max),
+                    ..
+                })) if path.is_ident("max_elements") && target == OptionsTarget::DeriveItem => {
+                    res.max_elements = Some(max.base10_parse()?);
+                }
+                NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Int(index),
+                    ..
+                })) if path.is_ident("tuple_field")
+                    && (target == OptionsTarget::NamedField
+                        || target == OptionsTarget::UnnamedField) =>
+                {
+                    res.print_type = FieldPrintType::TupleField(syn::Index {
+                        index: index.base10_parse()?,
+                        span: index.span(),
+                    });
+                }
+                NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Str(_),
+                    ..
+                })) if path.is_ident("preset") && target == OptionsTarget::DeriveItem => {}
+                NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Str(field),
+                    ..
+                })) if path.is_ident("field") && target == OptionsTarget::DeriveItem => {
+                    res.union_field = Some(field.clone());
+                }
+                NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Str(condition),
+                    ..
+                })) if path.is_ident("non_exhaustive_if")
+                    && target == OptionsTarget::DeriveItem =>
+                {
+                    let condition = syn::parse_str::<Expr>(&condition.value())
+                        .map_err(|e| syn::Error::new(condition.span(), e.to_string()))?;
+                    res.non_exhaustive_if = Some(condition);
+                }
+                NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Str(order),
+                    ..
+                })) if path.is_ident("order") && target == OptionsTarget::DeriveItem => {
+                    res.order = Some(order.clone());
+                }
+                NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Int(position),
+                    ..
+                })) if path.is_ident("order") && target == OptionsTarget::NamedField => {
+                    res.position = Some(position.base10_parse()?);
+                }
+                NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Str(names),
+                    ..
+                })) if path.is_ident("names_from") && target == OptionsTarget::DeriveItem => {
+                    let path = parse_fn_path(&names)?;
+                    res.names_from = Some(path);
+                }
+                NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Str(rename),
+                    ..
+                })) if path.is_ident("rename_all_with") && target == OptionsTarget::DeriveItem => {
+                    let path = parse_fn_path(&rename)?;
+                    res.rename_all_with = Some(path);
+                }
+                NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Str(translate),
+                    ..
+                })) if path.is_ident("i18n") && target == OptionsTarget::DeriveItem => {
+                    let path = parse_fn_path(&translate)?;
+                    res.i18n = Some(path);
+                }
+                NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Str(rule),
+                    ..
+                })) if path.is_ident("rename_all") && target == OptionsTarget::DeriveItem => {
+                    res.rename_all = Some(RenameRule::from_name(&rule.value()).ok_or_else(|| {
+                        syn::Error::new_spanned(&rule, "expected \"snake_case\", \"camelCase\", \"PascalCase\", \"SCREAMING_SNAKE_CASE\" or \"kebab-case\"")
+                    })?);
+                    rename_all = Some(rule);
+                }
+                NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Str(verbosity),
+                    ..
+                })) if path.is_ident("verbosity") && target == OptionsTarget::DeriveItem => {
+                    res.verbosity = Some(parse_fn_path(&verbosity)?)
+                }
+                NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Int(level),
+                    ..
+                })) if path.is_ident("level")
+                    && (target == OptionsTarget::NamedField
+                        || target == OptionsTarget::UnnamedField) =>
+                {
+                    res.level = Some(level)
+                }
+                NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Str(version),
+                    ..
+                })) if path.is_ident("version") && target == OptionsTarget::DeriveItem => {
+                    res.version = Some(version)
+                }
+                NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Str(name),
+                    ..
+                })) if path.is_ident("version_field") && target == OptionsTarget::DeriveItem => {
+                    res.version_field = Some(name)
+                }
+                NestedMeta::Meta(Meta::Path(path))
+                    if path.is_ident("show_size") && target == OptionsTarget::DeriveItem =>
+                {
+                    res.show_size = Some(LitStr::new("__size", path.span()))
+                }
+                NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Str(name),
+                    ..
+                })) if path.is_ident("show_size") && target == OptionsTarget::DeriveItem => {
+                    res.show_size = Some(name)
+                }
+                NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Str(bound),
+                    ..
+                })) if path.is_ident("bound") && target == OptionsTarget::DeriveItem => {
+                    // an empty string adds no bounds
+                    if !bound.value().trim().is_empty() {
+                        res.bounds.extend(bound.parse_with(
+                            Punctuated::<WherePredicate, Token![,]>::parse_terminated,
+                        )?);
+                    }
+                }
+                NestedMeta::Meta(Meta::List(list))
+                    if list.path.is_ident("skip_bound") && target == OptionsTarget::DeriveItem =>
+                {
+                    for param in &list.nested {
+                        match param {
+                            NestedMeta::Meta(Meta::Path(path)) if path.get_ident().is_some() => {
+                                res.skip_bound.push(path.get_ident().unwrap().clone())
+                            }
+                            _ => {
+                                return Err(syn::Error::new_spanned(
+                                    param,
+                                    "expected a type parameter",
+                                ))
+                            }
+                        }
+                    }
+                }
+                NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Str(krate),
+                    ..
+                })) if path.is_ident("crate_path") && target == OptionsTarget::DeriveItem => {
+                    // the span of the literal is kept for errors about options requiring std
+                    res.crate_path = match krate.value().as_str() {
+                        "std" | "core" => Ident::new(&krate.value(), krate.span()),
+                        _ => {
+                            return Err(syn::Error::new_spanned(
+                                krate,
+                                "expected \"std\" or \"core\"",
+                            ))
+                        }
+                    };
+                }
+                NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Str(style),
+                    ..
+                })) if path.is_ident("variant_style") && target == OptionsTarget::DeriveItem => {
+                    res.variant_style = Some(match style.value().as_str() {
+                        "short" => VariantStyle::Short,
+                        "qualified" => VariantStyle::Qualified,
+                        _ => {
+                            return Err(syn::Error::new_spanned(
+                                style,
+                                "expected \"short\" or \"qualified\"",
+                            ))
+                        }
+                    });
                 }
                 NestedMeta::Meta(Meta::NameValue(MetaNameValue {
                     path,
                     lit: Lit::Str(custom),
                     ..
-                })) if path.is_ident("formatter")
+                })) if path.is_ident("formatter_opt")
+                    && (target == OptionsTarget::NamedField
+                        || target == OptionsTarget::UnnamedField) =>
+                {
+                    let path = parse_fn_path(&custom)?;
+                    res.print_type = FieldPrintType::CustomOpt(path);
+                }
+                NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Str(custom),
+                    ..
+                })) if path.is_ident("option_formatter")
+                    && (target == OptionsTarget::NamedField
+                        || target == OptionsTarget::UnnamedField) =>
+                {
+                    res.print_type =
+                        FieldPrintType::OptionFormatter(parse_fn_path(&custom)?, "None".to_owned());
+                }
+                NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Str(marker),
+                    ..
+                })) if path.is_ident("none")
+                    && (target == OptionsTarget::NamedField
+                        || target == OptionsTarget::UnnamedField) =>
+                {
+                    none = Some(marker);
+                }
+                NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Str(custom),
+                    ..
+                })) if path.is_ident("alt_formatter")
+                    && (target == OptionsTarget::NamedField
+                        || target == OptionsTarget::UnnamedField) =>
+                {
+                    alt_formatter = Some((path, parse_fn_path(&custom)?));
+                }
+                NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Str(skip_if),
+                    ..
+                })) if path.is_ident("skip_if") && target != OptionsTarget::DeriveItem => {
+                    let path = parse_fn_path(&skip_if)?;
+                    res.skip_if = Some(path);
+                }
+                NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Str(expand_with),
+                    ..
+                })) if path.is_ident("expand_with") && target == OptionsTarget::NamedField => {
+                    let path = parse_fn_path(&expand_with)?;
+                    res.expand_with = Some(path);
+                }
+                NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Str(with),
+                    ..
+                })) if (path.is_ident("with")
+                    || path.is_ident("formatter_fmt")
+                    || path.is_ident("stream_with"))
                     && (target == OptionsTarget::NamedField
                         || target == OptionsTarget::UnnamedField) =>
                 {
-                    let path = syn::parse_str::<Path>(&custom.value()).map_err(|e| syn::Error::new(custom.span(), e.to_string()))?;
-                    res.print_type = FieldPrintType::Custom(path);
+                    let path = parse_fn_path(&with)?;
+                    res.print_type = FieldPrintType::With(path, Vec::new());
+                }
+                NestedMeta::Meta(Meta::List(list))
+                    if list.path.is_ident("with_args")
+                        && (target == OptionsTarget::NamedField
+                            || target == OptionsTarget::UnnamedField) =>
+                {
+                    let mut args = Vec::new();
+                    for arg in &list.nested {
+                        match arg {
+                            NestedMeta::Lit(lit)
+                            | NestedMeta::Meta(Meta::NameValue(MetaNameValue { lit, .. })) => {
+                                args.push(lit.clone())
+                            }
+                            _ => return Err(syn::Error::new_spanned(arg, "expected a literal")),
+                        }
+                    }
+                    with_args = Some((list, args));
                 }
                 _ => return Err(syn::Error::new_spanned(option, "invalid option")),
             }
         }
     }
 
+    if let (Some(rule), Some(_)) = (&rename_all, &res.rename_all_with) {
+        return Err(syn::Error::new_spanned(
+            rule,
+            "rename_all can not be combined with rename_all_with",
+        ));
+    }
+
+    if let Some((list, args)) = with_args {
+        match &mut res.print_type {
+            FieldPrintType::With(_, with_args) => *with_args = args,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    list,
+                    "with_args requires the with option",
+                ))
+            }
+        }
+    }
+
+    if let (Some(name), None) = (&res.version_field, &res.version) {
+        return Err(syn::Error::new_spanned(
+            name,
+            "version_field requires the version option",
+        ));
+    }
+
+    // the radix shorthands are resolved afterwards, so that conflicts are detected regardless of their order
+    if let Some(option) = radix {
+        if !matches!(res.print_type, FieldPrintType::Normal) {
+            return Err(syn::Error::new_spanned(
+                &option,
+                "hex, HEX, oct and bin can not be combined with other formatting options",
+            ));
+        }
+
+        let fmt = match option.get_ident().unwrap().to_string().as_str() {
+            "hex" => "{:#x}",
+            "HEX" => "{:#X}",
+            "oct" => "{:#o}",
+            _ => "{:#b}",
+        };
+        res.print_type = FieldPrintType::Format(LitStr::new(fmt, option.span()));
+    }
+
+    if let (Some(NameConcat { lit, .. }), Some(_)) = (&res.name_concat, &res.alias) {
+        return Err(syn::Error::new_spanned(
+            lit,
+            "name_concat can not be combined with alias",
+        ));
+    }
+
+    if let Some(option) = display {
+        if !matches!(res.print_type, FieldPrintType::Normal) {
+            return Err(syn::Error::new_spanned(
+                &option,
+                "display can not be combined with other formatting options",
+            ));
+        }
+        res.print_type = FieldPrintType::Format(LitStr::new("{}", option.span()));
+    }
+
+    check_std_only_options(&res, &res.crate_path, &res.crate_path)?;
+
+    if let Some((option, alt_formatter)) = alt_formatter {
+        match &res.print_type {
+            FieldPrintType::Custom(formatter) => {
+                res.print_type = FieldPrintType::CustomAlt(formatter.clone(), alt_formatter)
+            }
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    option,
+                    "alt_formatter requires the formatter option",
+                ))
+            }
+        }
+    }
+
+    if let Some(marker) = none {
+        match &mut res.print_type {
+            FieldPrintType::OptionFormatter(_, none) => *none = marker.value(),
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    marker,
+                    "none requires the option_formatter option",
+                ))
+            }
+        }
+    }
+
+    if let Some((field, member)) = precision_from {
+        match &res.print_type {
+            FieldPrintType::Format(fmt) if fmt.value().contains(".*") => {
+                res.print_type = FieldPrintType::FormatPrecision(fmt.clone(), member)
+            }
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    field,
+                    "precision_from requires the fmt option with a `.*` precision",
+                ))
+            }
+        }
+    }
+
+    if let Some(arg) = res.fmt_args.first() {
+        if !matches!(
+            res.print_type,
+            FieldPrintType::Format(_) | FieldPrintType::FormatPrecision(..)
+        ) {
+            return Err(syn::Error::new_spanned(
+                arg,
+                "format arguments can not be combined with other formatting options",
+            ));
+        }
+    }
+
+    if let Some(option) = &deref {
+        if !matches!(
+            res.print_type,
+            FieldPrintType::Normal | FieldPrintType::Skip
+        ) {
+            return Err(syn::Error::new_spanned(
+                option,
+                "deref can not be combined with other formatting options",
+            ));
+        }
+        res.deref = true;
+    }
+
+    if let Some(expand_with) = &res.expand_with {
+        if !matches!(
+            res.print_type,
+            FieldPrintType::Normal | FieldPrintType::Skip
+        ) {
+            return Err(syn::Error::new_spanned(
+                expand_with,
+                "expand_with can not be combined with other formatting options",
+            ));
+        }
+    }
+
+    // the cache is only keyed by the field, so the output must not depend on anything else
+    if let Some(option) = &cache_format {
+        if !res.fmt_args.is_empty()
+            || matches!(res.print_type, FieldPrintType::FormatPrecision(..))
+            || res.level.is_some()
+        {
+            return Err(syn::Error::new_spanned(
+                option,
+                "cache_format can not be combined with format arguments, precision_from or level",
+            ));
+        }
+        res.cache_format = true;
+    }
+
+    Ok(res)
+}
+
+/// Parses the path of a function supplied by the user.
+///
+/// The path is resolved at the mixed site, so that the local variables of the generated code,
+/// e.g. the formatter `f`, can not shadow a function of the same name. Errors still point at `lit`.
+fn parse_fn_path(lit: &LitStr) -> Result<Path, syn::Error> {
+    let path: Path = lit.parse()?;
+    let span = Span::mixed_site().located_at(lit.span());
+    syn::parse2(respan(path.into_token_stream(), span))
+}
+
+fn respan(tokens: TokenStream, span: Span) -> TokenStream {
+    tokens
+        .into_iter()
+        .map(|token| match token {
+            TokenTree::Group(group) => {
+                let mut res = Group::new(group.delimiter(), respan(group.stream(), span));
+                res.set_span(span);
+                TokenTree::Group(res)
+            }
+            mut token => {
+                token.set_span(span);
+                token
+            }
+        })
+        .collect()
+}
+
+/// Returns the primitive integer type specified in a `#[repr(...)]` attribute.
+fn find_repr(attributes: &[Attribute]) -> Option<Ident> {
+    const PRIMITIVES: [&str; 12] = [
+        "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize",
+    ];
+
+    attributes
+        .iter()
+        .filter(|attrib| attrib.path.is_ident("repr"))
+        .filter_map(|attrib| match attrib.parse_meta() {
+            Ok(Meta::List(list)) => Some(list.nested),
+            _ => None,
+        })
+        .flatten()
+        .find_map(|option| match option {
+            NestedMeta::Meta(Meta::Path(path)) => path
+                .get_ident()
+                .filter(|ident| PRIMITIVES.iter().any(|primitive| *ident == primitive))
+                .cloned(),
+            _ => None,
+        })
+}
+
+/// Returns the value of the `preset` option in `attributes`, if there is one.
+fn find_preset(attributes: &[Attribute]) -> Result<Option<LitStr>, syn::Error> {
+    let mut res = None;
+
+    for attrib in attributes {
+        if !attrib.path.is_ident("dbg") || group::is_group_attribute(attrib) {
+            continue;
+        }
+
+        if let Meta::List(list) = attrib.parse_meta()? {
+            for option in list.nested {
+                if let NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Str(preset),
+                    ..
+                })) = option
+                {
+                    if path.is_ident("preset") {
+                        res = Some(preset);
+                    }
+                }
+            }
+        }
+    }
+
     Ok(res)
 }
+
+/// Sets the options bundled in the built-in preset called `preset`.
+fn apply_preset(options: &mut FieldOutputOptions, preset: &LitStr) -> Result<(), syn::Error> {
+    match preset.value().as_str() {
+        "Compact" => options.max_elements = Some(3),
+        "Verbose" => {
+            options.show_types = true;
+            options.crate_prefixed = true;
+        }
+        "Secure" => options.default_placeholder = Some("<redacted>".to_owned()),
+        _ => {
+            return Err(syn::Error::new_spanned(
+                preset,
+                "expected \"Compact\", \"Verbose\" or \"Secure\"",
+            ))
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inline() {
+        let item = syn::parse_quote! {
+            #[dbg(inline)]
+            struct Foo(u32);
+        };
+        let expansion = derive_debug_impl(item).to_string();
+        assert!(expansion.contains("# [inline] fn fmt"), "{}", expansion);
+
+        let item = syn::parse_quote! {
+            struct Foo(u32);
+        };
+        let expansion = derive_debug_impl(item).to_string();
+        assert!(!expansion.contains("# [inline]"), "{}", expansion);
+    }
+
+    #[test]
+    fn test_crate_path_core() {
+        let item = syn::parse_quote! {
+            #[dbg(crate_path = "core")]
+            struct Foo<T> {
+                a: u32,
+                #[dbg(fmt = "{:#x}")]
+                b: u32,
+                #[dbg(skip_if = "Option::is_none")]
+                c: Option<T>,
+            }
+        };
+        let expansion = derive_debug_impl(item).to_string();
+        assert!(
+            expansion.contains("impl < T > :: core :: fmt :: Debug for Foo < T >"),
+            "{}",
+            expansion
+        );
+        assert!(!expansion.contains(":: std"), "{}", expansion);
+
+        let item = syn::parse_quote! {
+            struct Foo(u32);
+        };
+        let expansion = derive_debug_impl(item).to_string();
+        assert!(
+            expansion.contains("use :: std :: fmt as __dbg_fmt"),
+            "{}",
+            expansion
+        );
+    }
+
+    #[test]
+    fn test_std_only_options_documented() {
+        let options = parse_options(&[], OptionsTarget::DeriveItem).unwrap();
+        let names: Vec<_> = std_only_options(&options)
+            .iter()
+            .map(|(option, _)| format!("`{}`", option))
+            .collect();
+        let (last, rest) = names.split_last().unwrap();
+        let list = format!("{} and {}.", rest.join(", "), last);
+        assert!(include_str!("../README.md").contains(&list), "{}", list);
+    }
+
+    #[test]
+    fn test_order_unknown_field() {
+        let item = syn::parse_quote! {
+            #[dbg(order = "b, c")]
+            struct Foo {
+                a: u32,
+                b: u32,
+            }
+        };
+        let expansion = derive_debug_impl(item).to_string();
+        assert!(
+            expansion.contains("unknown or duplicate field `c`"),
+            "{}",
+            expansion
+        );
+
+        let item = syn::parse_quote! {
+            #[dbg(order = "a, a")]
+            struct Foo {
+                a: u32,
+            }
+        };
+        let expansion = derive_debug_impl(item).to_string();
+        assert!(
+            expansion.contains("unknown or duplicate field `a`"),
+            "{}",
+            expansion
+        );
+    }
+}
@@ -0,0 +1,128 @@
+//! Parsing of the `name_concat` option, which builds the type name from constants at compile time.
+//!
+//! The items are written as a comma separated list inside the string literal:
+//! `#[dbg(name_concat = "PREFIX, \"::\", NAME")]`
+
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{Lit, LitStr, Path, Token};
+
+/// The parsed value of the `name_concat` option.
+pub(crate) struct NameConcat {
+    pub(crate) lit: LitStr,
+    pub(crate) parts: Vec<NamePart>,
+}
+
+/// An item of the `name_concat` list.
+#[derive(Clone)]
+pub(crate) enum NamePart {
+    /// Tokens accepted by `concat!`, e.g. a literal.
+    Lit(TokenStream),
+    /// Path to a `&str` constant.
+    Const(Path),
+}
+
+impl Parse for NamePart {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if !input.peek(Lit) {
+            return Ok(Self::Const(input.parse()?));
+        }
+
+        // `concat!` rejects suffixed numbers like `1u8`
+        match input.parse()? {
+            Lit::Int(lit) if !lit.suffix().is_empty() => Err(syn::Error::new_spanned(
+                lit,
+                "name_concat does not support literal suffixes",
+            )),
+            Lit::Float(lit) if !lit.suffix().is_empty() => Err(syn::Error::new_spanned(
+                lit,
+                "name_concat does not support literal suffixes",
+            )),
+            lit @ (Lit::Str(_) | Lit::Char(_) | Lit::Int(_) | Lit::Float(_) | Lit::Bool(_)) => {
+                Ok(Self::Lit(lit.into_token_stream()))
+            }
+            lit => Err(syn::Error::new_spanned(
+                lit,
+                "name_concat only supports string, character, number and boolean literals",
+            )),
+        }
+    }
+}
+
+/// Parses the comma separated items of `lit`.
+pub(crate) fn parse_name_concat(lit: LitStr) -> syn::Result<NameConcat> {
+    let parts: Vec<_> = lit
+        .parse_with(Punctuated::<NamePart, Token![,]>::parse_terminated)?
+        .into_iter()
+        .collect();
+    if parts.is_empty() {
+        return Err(syn::Error::new_spanned(
+            lit,
+            "name_concat requires at least one item",
+        ));
+    }
+
+    Ok(NameConcat { lit, parts })
+}
+
+/// Generates an expression evaluating to the concatenation of `parts` as `&'static str`.
+///
+/// Only literals are expanded to a plain `concat!`, as it does not accept constants.
+/// Otherwise the parts are copied into a byte array by a constant expression.
+pub(crate) fn derive_concat(parts: &[NamePart]) -> TokenStream {
+    let lits: Option<Vec<_>> = parts
+        .iter()
+        .map(|part| match part {
+            NamePart::Lit(lit) => Some(lit),
+            NamePart::Const(_) => None,
+        })
+        .collect();
+    if let Some(lits) = lits {
+        return quote! { concat!(#(#lits),*) };
+    }
+
+    let parts = parts.iter().map(|part| match part {
+        NamePart::Lit(lit) => quote! { concat!(#lit) },
+        NamePart::Const(path) => quote! { #path },
+    });
+
+    quote! {
+        {
+            // the constants are prefixed, as they would otherwise shadow constants of the same name
+            const __DBG_PARTS: &[&str] = &[#(#parts),*];
+            const __DBG_LEN: usize = {
+                let mut len = 0;
+                let mut i = 0;
+                while i < __DBG_PARTS.len() {
+                    len += __DBG_PARTS[i].len();
+                    i += 1;
+                }
+                len
+            };
+            const __DBG_BYTES: [u8; __DBG_LEN] = {
+                let mut bytes = [0; __DBG_LEN];
+                let mut len = 0;
+                let mut i = 0;
+                while i < __DBG_PARTS.len() {
+                    let part = __DBG_PARTS[i].as_bytes();
+                    let mut j = 0;
+                    while j < part.len() {
+                        bytes[len] = part[j];
+                        len += 1;
+                        j += 1;
+                    }
+                    i += 1;
+                }
+                bytes
+            };
+            // the parts are valid UTF-8, so their concatenation is as well
+            const __DBG_NAME: &str = match ::core::str::from_utf8(&__DBG_BYTES) {
+                Ok(name) => name,
+                Err(_) => panic!("name_concat produced invalid UTF-8"),
+            };
+            __DBG_NAME
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use derive_debug::Dbg;
+
+struct Inner {
+    name: &'static str,
+    id: u32,
+}
+
+struct Outer {
+    inner: Inner,
+    pair: (u8, u16),
+}
+
+#[derive(Dbg)]
+struct TestTuple(
+    u8,
+    #[dbg(access = "inner.name")] Outer,
+    #[dbg(access = "pair.1", fmt = "{:#x}")] Outer,
+);
+
+#[derive(Dbg)]
+struct TestNamed {
+    #[dbg(access = "inner", formatter = "inner_id")]
+    outer: Outer,
+    #[dbg(access = " 0 . name ")]
+    tuple: (Inner,),
+}
+
+#[derive(Dbg)]
+enum TestEnum {
+    Tuple(#[dbg(access = "inner.id")] Outer),
+    Struct {
+        #[dbg(access = "pair.0")]
+        outer: Outer,
+    },
+}
+
+fn inner_id(inner: &Inner) -> String {
+    format!("#{}", inner.id)
+}
+
+fn outer(name: &'static str, id: u32) -> Outer {
+    Outer {
+        inner: Inner { name, id },
+        pair: (id as u8, 0xff),
+    }
+}
+
+#[test]
+fn test_struct() {
+    let s = TestTuple(1, outer("a", 2), outer("b", 3));
+    assert_eq!(format!("{:?}", s), r#"TestTuple(1, "a", 0xff)"#);
+
+    let s = TestNamed {
+        outer: outer("c", 4),
+        tuple: (Inner { name: "d", id: 5 },),
+    };
+    assert_eq!(format!("{:?}", s), r#"TestNamed { outer: #4, tuple: "d" }"#);
+}
+
+#[test]
+fn test_enum() {
+    assert_eq!(format!("{:?}", TestEnum::Tuple(outer("e", 6))), "Tuple(6)");
+    assert_eq!(
+        format!(
+            "{:?}",
+            TestEnum::Struct {
+                outer: outer("f", 7)
+            }
+        ),
+        "Struct { outer: 7 }"
+    );
+}
@@ -0,0 +1,53 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+#[dbg(alias_suffix = " (debug)")]
+struct TestSuffix {
+    a: u32,
+}
+
+#[derive(Dbg)]
+#[dbg(alias_prefix = "Experimental")]
+struct TestPrefix(u32);
+
+#[derive(Dbg)]
+#[dbg(alias_prefix = "My", alias = "Alias", alias_suffix = "V2")]
+struct TestAlias;
+
+#[derive(Dbg)]
+#[dbg(
+    alias_suffix = "V2",
+    alias = "Alias",
+    alias_prefix = "My",
+    crate_prefixed
+)]
+struct TestCratePrefixed;
+
+#[derive(Dbg)]
+#[dbg(
+    alias_prefix = "Pre",
+    alias_suffix = "Post",
+    variant_style = "qualified"
+)]
+enum TestEnum {
+    Unit,
+}
+
+#[test]
+fn test_struct() {
+    assert_eq!(
+        format!("{:?}", TestSuffix { a: 1 }),
+        "TestSuffix (debug) { a: 1 }"
+    );
+    assert_eq!(format!("{:?}", TestPrefix(1)), "ExperimentalTestPrefix(1)");
+    assert_eq!(format!("{:?}", TestAlias), "MyAliasV2");
+    assert_eq!(
+        format!("{:?}", TestCratePrefixed),
+        "derive-debug::MyAliasV2"
+    );
+}
+
+#[test]
+fn test_enum() {
+    assert_eq!(format!("{:?}", TestEnum::Unit), "PreTestEnumPost::Unit");
+}
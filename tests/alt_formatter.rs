@@ -0,0 +1,33 @@
+use derive_debug::Dbg;
+
+fn compact(v: &u64) -> String {
+    format!("{}s", v)
+}
+
+fn verbose(v: &u64) -> String {
+    format!("{} seconds", v)
+}
+
+#[derive(Dbg)]
+struct Foo {
+    #[dbg(formatter = "compact", alt_formatter = "verbose")]
+    timeout: u64,
+}
+
+#[derive(Dbg)]
+enum Bar {
+    Tuple(#[dbg(alt_formatter = "verbose", formatter = "compact")] u64),
+}
+
+#[test]
+fn test_alt_formatter() {
+    let foo = Foo { timeout: 30 };
+    assert_eq!(format!("{:?}", foo), "Foo { timeout: 30s }");
+    assert_eq!(format!("{:#?}", foo), "Foo {\n    timeout: 30 seconds,\n}");
+}
+
+#[test]
+fn test_alt_formatter_variant() {
+    assert_eq!(format!("{:?}", Bar::Tuple(5)), "Tuple(5s)");
+    assert_eq!(format!("{:#?}", Bar::Tuple(5)), "Tuple(\n    5 seconds,\n)");
+}
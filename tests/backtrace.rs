@@ -0,0 +1,36 @@
+use std::backtrace::Backtrace;
+
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+struct TestError {
+    message: &'static str,
+    #[dbg(backtrace)]
+    backtrace: Backtrace,
+}
+
+#[derive(Dbg)]
+struct TestTuple(#[dbg(backtrace)] Backtrace);
+
+#[test]
+fn test_disabled() {
+    let foo = TestError {
+        message: "oops",
+        backtrace: Backtrace::disabled(),
+    };
+    assert_eq!(
+        format!("{:?}", foo),
+        r#"TestError { message: "oops", backtrace: <disabled> }"#
+    );
+}
+
+#[test]
+fn test_captured() {
+    let foo = TestTuple(Backtrace::force_capture());
+    let output = format!("{:?}", foo);
+    assert!(
+        output == "TestTuple(<backtrace captured>)" || output == "TestTuple(<unsupported>)",
+        "{}",
+        output
+    );
+}
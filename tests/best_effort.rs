@@ -0,0 +1,117 @@
+use std::fmt::{self, Write};
+
+use derive_debug::Dbg;
+
+/// Type whose [`Debug`](fmt::Debug) implementation always fails.
+struct Failing;
+
+impl fmt::Debug for Failing {
+    fn fmt(&self, _: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Err(fmt::Error)
+    }
+}
+
+/// Type that writes a string rejected by [`RejectingWriter`].
+struct Rejected;
+
+impl fmt::Debug for Rejected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("rejected")
+    }
+}
+
+/// Writer failing on every write of `"rejected"`.
+#[derive(Default)]
+struct RejectingWriter(String);
+
+impl Write for RejectingWriter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if s == "rejected" {
+            return Err(fmt::Error);
+        }
+        self.0.push_str(s);
+        Ok(())
+    }
+}
+
+#[derive(Dbg)]
+#[dbg(best_effort, alias = "Foo")]
+#[allow(dead_code)]
+struct TestStruct {
+    a: u32,
+    #[dbg(alias = "failing")]
+    b: Failing,
+    #[dbg(skip)]
+    c: u32,
+    d: Rejected,
+}
+
+#[derive(Dbg)]
+#[dbg(best_effort)]
+struct TestTuple(u32, Failing, #[dbg(fmt = "{:#X}")] u32);
+
+#[derive(Dbg)]
+#[dbg(best_effort)]
+#[allow(dead_code)]
+enum TestEnum {
+    Unit,
+    Named {
+        a: u32,
+        b: Failing,
+    },
+    Tuple(Failing, u32),
+    #[dbg(skip)]
+    Skipped(u32),
+}
+
+#[test]
+fn test_struct() {
+    let foo = TestStruct {
+        a: 1,
+        b: Failing,
+        c: 2,
+        d: Rejected,
+    };
+    assert_eq!(
+        format!("{:?}", foo),
+        "Foo { a: 1, failing: <error>, d: rejected, .. }"
+    );
+
+    let mut writer = RejectingWriter::default();
+    assert!(write!(writer, "{:?}", foo).is_ok());
+    assert_eq!(writer.0, "Foo { a: 1, failing: <error>, d: <error>, .. }");
+}
+
+#[test]
+fn test_struct_pretty() {
+    let foo = TestStruct {
+        a: 1,
+        b: Failing,
+        c: 2,
+        d: Rejected,
+    };
+    assert_eq!(
+        format!("{:#?}", foo),
+        "Foo { a: 1, failing: <error>, d: rejected, .. }"
+    );
+}
+
+#[test]
+fn test_tuple() {
+    let foo = TestTuple(1, Failing, 255);
+    assert_eq!(format!("{:?}", foo), "TestTuple(1, <error>, 0xFF)");
+}
+
+#[test]
+fn test_enum() {
+    assert_eq!(format!("{:?}", TestEnum::Unit), "Unit");
+    assert_eq!(
+        format!("{:?}", TestEnum::Named { a: 1, b: Failing }),
+        "Named { a: 1, b: <error> }"
+    );
+    assert_eq!(
+        format!("{:?}", TestEnum::Tuple(Failing, 1)),
+        "Tuple(<error>, 1)"
+    );
+    assert_eq!(format!("{:?}", TestEnum::Skipped(1)), "Skipped");
+}
@@ -0,0 +1,56 @@
+use std::fmt::{Debug, Display};
+use std::marker::PhantomData;
+
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+#[dbg(bound = "T: Debug")]
+struct Wrapper<T> {
+    value: T,
+}
+
+#[derive(Dbg)]
+#[dbg(bound = "A: Debug", bound = "B: Display")]
+struct Pair<A, B>(A, #[dbg(fmt = "{}")] B);
+
+#[derive(Dbg)]
+#[dbg(bound = "K: Debug, V: Debug")]
+enum Entry<K, V>
+where
+    K: Ord,
+{
+    Occupied(K, V),
+    Vacant(K),
+}
+
+struct NotDebug;
+
+#[derive(Dbg)]
+#[dbg(bound = "")]
+struct Marker<T> {
+    id: u32,
+    marker: PhantomData<T>,
+}
+
+#[test]
+fn test_bound() {
+    assert_eq!(
+        format!("{:?}", Wrapper { value: 42 }),
+        "Wrapper { value: 42 }"
+    );
+    assert_eq!(format!("{:?}", Pair(1, "a")), "Pair(1, a)");
+    assert_eq!(format!("{:?}", Entry::Occupied(1, 'x')), "Occupied(1, 'x')");
+    assert_eq!(format!("{:?}", Entry::<u32, char>::Vacant(2)), "Vacant(2)");
+}
+
+#[test]
+fn test_empty_bound() {
+    let marker = Marker::<NotDebug> {
+        id: 1,
+        marker: PhantomData,
+    };
+    assert_eq!(
+        format!("{:?}", marker),
+        "Marker { id: 1, marker: PhantomData<bound::NotDebug> }"
+    );
+}
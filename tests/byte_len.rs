@@ -0,0 +1,37 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+struct TestStruct {
+    #[dbg(byte_len)]
+    payload: Vec<u8>,
+    #[dbg(byte_len)]
+    header: [u8; 4],
+    #[dbg(byte_len)]
+    text: String,
+}
+
+#[derive(Dbg)]
+enum TestEnum {
+    Tuple(#[dbg(byte_len)] &'static [u8]),
+}
+
+#[test]
+fn test_struct() {
+    let foo = TestStruct {
+        payload: vec![0; 1024],
+        header: [1, 2, 3, 4],
+        text: "abc".to_string(),
+    };
+    assert_eq!(
+        format!("{:?}", foo),
+        "TestStruct { payload: <1024 bytes>, header: <4 bytes>, text: <3 bytes> }"
+    );
+}
+
+#[test]
+fn test_enum() {
+    assert_eq!(
+        format!("{:?}", TestEnum::Tuple(b"hello")),
+        "Tuple(<5 bytes>)"
+    );
+}
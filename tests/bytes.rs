@@ -0,0 +1,41 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+struct TestStruct<'a> {
+    #[dbg(bytes)]
+    array: [u8; 4],
+    #[dbg(bytes)]
+    slice: &'a [u8],
+    #[dbg(bytes)]
+    boxed: Box<[u8]>,
+}
+
+#[derive(Dbg)]
+struct TestPretty(#[dbg(bytes = "pretty")] Vec<u8>);
+
+#[test]
+fn test_bytes() {
+    let s = TestStruct {
+        array: [0xde, 0xad, 0xbe, 0xef],
+        slice: &[0, 1, 0x7f, 0xff],
+        boxed: Box::new([]),
+    };
+    assert_eq!(
+        format!("{:?}", s),
+        "TestStruct { array: deadbeef, slice: 00017fff, boxed:  }"
+    );
+}
+
+#[test]
+fn test_pretty() {
+    let data: Vec<u8> = (0..20).collect();
+    assert_eq!(
+        format!("{:?}", TestPretty(data.clone())),
+        "TestPretty(00 01 02 03 04 05 06 07  08 09 0a 0b 0c 0d 0e 0f  10 11 12 13)"
+    );
+    assert_eq!(
+        format!("{:#?}", TestPretty(data)),
+        "TestPretty(\n    00000000: 00 01 02 03 04 05 06 07  08 09 0a 0b 0c 0d 0e 0f\n    00000010: 10 11 12 13,\n)"
+    );
+    assert_eq!(format!("{:?}", TestPretty(vec![])), "TestPretty()");
+}
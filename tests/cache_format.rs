@@ -0,0 +1,112 @@
+use std::cell::Cell;
+
+use derive_debug::Dbg;
+
+thread_local! {
+    static CALLS: Cell<usize> = const { Cell::new(0) };
+}
+
+fn counted(v: &u32) -> String {
+    CALLS.with(|calls| calls.set(calls.get() + 1));
+    format!("<{}>", v)
+}
+
+fn calls() -> usize {
+    CALLS.with(Cell::get)
+}
+
+#[derive(Dbg)]
+struct Foo {
+    #[dbg(cache_format, formatter = "counted")]
+    value: u32,
+    #[dbg(cache_format)]
+    items: Vec<u32>,
+}
+
+#[derive(Dbg)]
+#[dbg(bound = "T: std::fmt::Debug + std::hash::Hash")]
+struct Generic<T> {
+    #[dbg(cache_format)]
+    value: T,
+}
+
+#[derive(Dbg)]
+struct W {
+    #[dbg(cache_format)]
+    x: u32,
+}
+
+#[derive(Dbg)]
+enum TestEnum {
+    Struct {
+        #[dbg(placeholder = "x", cache_format)]
+        a: u32,
+        #[dbg(fmt = "{}-", cache_format)]
+        b: u32,
+        #[dbg(cache_format, formatter = "counted")]
+        c: u32,
+    },
+    Tuple(#[dbg(redact, cache_format)] u32),
+}
+
+#[test]
+fn test_cache_format() {
+    let mut foo = Foo {
+        value: 1,
+        items: vec![1, 2],
+    };
+    let before = calls();
+    assert_eq!(format!("{:?}", foo), "Foo { value: <1>, items: [1, 2] }");
+    assert_eq!(format!("{:?}", foo), "Foo { value: <1>, items: [1, 2] }");
+    assert_eq!(calls() - before, 1);
+
+    // a changed value is formatted again
+    foo.value = 2;
+    foo.items.push(3);
+    assert_eq!(format!("{:?}", foo), "Foo { value: <2>, items: [1, 2, 3] }");
+    assert_eq!(calls() - before, 2);
+}
+
+#[test]
+fn test_cache_format_alternate() {
+    let foo = Foo {
+        value: 7,
+        items: vec![1],
+    };
+    assert_eq!(format!("{:?}", foo), "Foo { value: <7>, items: [1] }");
+    assert_eq!(
+        format!("{:#?}", foo),
+        "Foo {\n    value: <7>,\n    items: [\n        1,\n    ],\n}"
+    );
+    assert_eq!(format!("{:?}", foo), "Foo { value: <7>, items: [1] }");
+}
+
+#[test]
+fn test_cache_format_generic() {
+    assert_eq!(
+        format!("{:?}", Generic { value: 1u8 }),
+        "Generic { value: 1 }"
+    );
+    assert_eq!(
+        format!("{:?}", Generic { value: "1" }),
+        "Generic { value: \"1\" }"
+    );
+}
+
+#[test]
+fn test_cache_format_enum() {
+    let value = TestEnum::Struct { a: 1, b: 2, c: 3 };
+    assert_eq!(format!("{:?}", value), "Struct { a: x, b: 2-, c: <3> }");
+    assert_eq!(format!("{:?}", value), "Struct { a: x, b: 2-, c: <3> }");
+    assert_eq!(format!("{:?}", TestEnum::Tuple(4)), "Tuple(***)");
+}
+
+#[test]
+fn test_cache_format_flags() {
+    let w = W { x: 1 };
+    assert_eq!(format!("{:?}", w), "W { x: 1 }");
+    // flags are applied even though the output is cached for the same value
+    assert_eq!(format!("{:5?}", w), "W { x:     1 }");
+    assert_eq!(format!("{:+?}", w), "W { x: +1 }");
+    assert_eq!(format!("{:?}", w), "W { x: 1 }");
+}
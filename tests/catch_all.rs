@@ -0,0 +1,35 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+enum TestEnum {
+    Unit,
+    Tuple(u32, u32),
+    #[dbg(catch_all)]
+    Other(String),
+    #[dbg(catch_all)]
+    Code {
+        #[dbg(fmt = "{:#X}")]
+        code: u32,
+    },
+}
+
+#[test]
+fn test_normal_variants() {
+    assert_eq!(format!("{:?}", TestEnum::Unit), "Unit");
+    assert_eq!(format!("{:?}", TestEnum::Tuple(1, 2)), "Tuple(1, 2)");
+}
+
+#[test]
+fn test_catch_all_variants() {
+    assert_eq!(
+        format!("{:?}", TestEnum::Other("other".to_string())),
+        r#""other""#
+    );
+    assert_eq!(format!("{:?}", TestEnum::Code { code: 255 }), "0xFF");
+}
+
+#[test]
+fn test_nested() {
+    let foo = vec![TestEnum::Unit, TestEnum::Other("other".to_string())];
+    assert_eq!(format!("{:?}", foo), r#"[Unit, "other"]"#);
+}
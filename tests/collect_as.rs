@@ -0,0 +1,28 @@
+use std::collections::BTreeSet;
+
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+struct TestStruct {
+    #[dbg(collect_as = "BTreeSet<u32>")]
+    ids: Vec<u32>,
+}
+
+#[derive(Dbg)]
+enum TestEnum {
+    Tuple(#[dbg(collect_as = "std::collections::BTreeSet<char>")] Vec<char>),
+}
+
+#[test]
+fn test_struct() {
+    let foo = TestStruct {
+        ids: vec![3, 1, 2, 1, 3],
+    };
+    assert_eq!(format!("{:?}", foo), "TestStruct { ids: {1, 2, 3} }");
+}
+
+#[test]
+fn test_enum() {
+    let foo = TestEnum::Tuple(vec!['b', 'a', 'b']);
+    assert_eq!(format!("{:?}", foo), "Tuple({'a', 'b'})");
+}
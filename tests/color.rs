@@ -0,0 +1,39 @@
+#![cfg(feature = "color")]
+
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+#[dbg(color)]
+struct Colored {
+    field_a: u32,
+    #[dbg(alias = "b")]
+    field_b: bool,
+}
+
+#[derive(Dbg)]
+struct Plain {
+    field_a: u32,
+}
+
+// environment variables are shared by all tests, so every case lives in one test
+#[test]
+fn test_color() {
+    let colored = Colored {
+        field_a: 42,
+        field_b: true,
+    };
+
+    std::env::set_var("CLICOLOR_FORCE", "1");
+    std::env::remove_var("NO_COLOR");
+    assert_eq!(
+        format!("{:?}", colored),
+        "Colored { \x1b[36mfield_a\x1b[0m: 42, \x1b[36mb\x1b[0m: true }"
+    );
+    assert_eq!(
+        format!("{:?}", Plain { field_a: 42 }),
+        "Plain { field_a: 42 }"
+    );
+
+    std::env::set_var("NO_COLOR", "1");
+    assert_eq!(format!("{:?}", colored), "Colored { field_a: 42, b: true }");
+}
@@ -0,0 +1,63 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+struct Buf<const N: usize>([u8; N]);
+
+#[derive(Dbg)]
+struct Named<T: std::fmt::Debug, const N: usize, const HEX: bool = false>
+where
+    T: Copy,
+{
+    values: [T; N],
+    #[dbg(fmt = "{:#x}")]
+    len: usize,
+}
+
+#[derive(Dbg)]
+#[dbg(show_size)]
+struct Sized<const N: usize> {
+    #[dbg(bytes)]
+    data: [u8; N],
+}
+
+#[derive(Dbg)]
+enum Either<const N: usize, const M: usize> {
+    Left([u8; N]),
+    Right {
+        #[dbg(formatter = "sum")]
+        data: [u32; M],
+    },
+}
+
+fn sum<const M: usize>(data: &[u32; M]) -> u32 {
+    data.iter().sum()
+}
+
+#[test]
+fn test_struct() {
+    assert_eq!(format!("{:?}", Buf([1, 2, 3])), "Buf([1, 2, 3])");
+    assert_eq!(format!("{:?}", Buf::<0>([])), "Buf([])");
+
+    let n: Named<i8, 2, true> = Named {
+        values: [-1, 1],
+        len: 16,
+    };
+    assert_eq!(format!("{:?}", n), "Named { values: [-1, 1], len: 0x10 }");
+
+    assert_eq!(
+        format!("{:?}", Sized { data: [0xab; 3] }),
+        "Sized { data: ababab, __size: 3 }"
+    );
+}
+
+#[test]
+fn test_enum() {
+    assert_eq!(
+        format!("{:?}", Either::<2, 0>::Left([4, 5])),
+        "Left([4, 5])"
+    );
+    assert_eq!(
+        format!("{:?}", Either::<0, 3>::Right { data: [1, 2, 3] }),
+        "Right { data: 6 }"
+    );
+}
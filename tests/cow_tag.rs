@@ -0,0 +1,50 @@
+use derive_debug::Dbg;
+use std::borrow::Cow;
+
+#[derive(Dbg)]
+struct Token<'a> {
+    #[dbg(cow_tag)]
+    text: Cow<'a, str>,
+    #[dbg(cow_tag)]
+    bytes: Cow<'a, [u8]>,
+}
+
+#[derive(Dbg)]
+struct Tuple<'a>(#[dbg(cow_tag)] Cow<'a, str>);
+
+#[derive(Dbg)]
+enum Segment<'a> {
+    Text(#[dbg(cow_tag)] Cow<'a, str>),
+}
+
+#[test]
+fn test_cow_tag_borrowed() {
+    let token = Token {
+        text: Cow::Borrowed("abc"),
+        bytes: Cow::Borrowed(&[1, 2]),
+    };
+    assert_eq!(
+        format!("{:?}", token),
+        "Token { text: Borrowed(\"abc\"), bytes: Borrowed([1, 2]) }"
+    );
+    assert_eq!(
+        format!("{:?}", Tuple(Cow::Borrowed("x"))),
+        "Tuple(Borrowed(\"x\"))"
+    );
+}
+
+#[test]
+fn test_cow_tag_owned() {
+    let token = Token {
+        text: Cow::Owned("abc".to_owned()),
+        bytes: Cow::Owned(vec![1, 2]),
+    };
+    assert_eq!(
+        format!("{:?}", token),
+        "Token { text: Owned(\"abc\"), bytes: Owned([1, 2]) }"
+    );
+    assert_eq!(
+        format!("{:?}", Segment::Text(Cow::Owned("x".to_owned()))),
+        "Text(Owned(\"x\"))"
+    );
+}
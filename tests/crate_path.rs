@@ -0,0 +1,32 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+#[dbg(crate_path = "core")]
+struct Foo {
+    #[dbg(fmt = "{:#x}")]
+    a: u32,
+    #[dbg(skip_if = "Option::is_none")]
+    b: Option<u32>,
+}
+
+#[derive(Dbg)]
+#[dbg(crate_path = "core")]
+enum Bar {
+    A(u32),
+    B { value: bool },
+}
+
+#[derive(Dbg)]
+#[dbg(crate_path = "std")]
+struct Baz(u32);
+
+#[test]
+fn test_crate_path() {
+    assert_eq!(format!("{:?}", Foo { a: 255, b: None }), "Foo { a: 0xff }");
+    assert_eq!(format!("{:?}", Bar::A(1)), "A(1)");
+    assert_eq!(
+        format!("{:#?}", Bar::B { value: true }),
+        "B {\n    value: true,\n}"
+    );
+    assert_eq!(format!("{:?}", Baz(1)), "Baz(1)");
+}
@@ -0,0 +1,50 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+#[dbg(crate_prefixed)]
+struct TestStruct {
+    a: u32,
+}
+
+#[derive(Dbg)]
+#[dbg(crate_prefixed, alias = "Aliased")]
+struct TestTuple(u32);
+
+#[derive(Dbg)]
+#[dbg(crate_prefixed)]
+struct TestUnit;
+
+#[derive(Dbg)]
+#[dbg(crate_prefixed, variant_style = "qualified")]
+enum TestEnum {
+    Unit,
+    Tuple(u32),
+    #[dbg(alias = "Aliased")]
+    AliasedUnit,
+}
+
+#[test]
+fn test_struct() {
+    assert_eq!(
+        format!("{:?}", TestStruct { a: 1 }),
+        "derive-debug::TestStruct { a: 1 }"
+    );
+    assert_eq!(format!("{:?}", TestTuple(1)), "derive-debug::Aliased(1)");
+    assert_eq!(format!("{:?}", TestUnit), "derive-debug::TestUnit");
+}
+
+#[test]
+fn test_enum() {
+    assert_eq!(
+        format!("{:?}", TestEnum::Unit),
+        "derive-debug::TestEnum::Unit"
+    );
+    assert_eq!(
+        format!("{:?}", TestEnum::Tuple(1)),
+        "derive-debug::TestEnum::Tuple(1)"
+    );
+    assert_eq!(
+        format!("{:?}", TestEnum::AliasedUnit),
+        "derive-debug::TestEnum::Aliased"
+    );
+}
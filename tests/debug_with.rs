@@ -0,0 +1,42 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+struct RangeView {
+    start: u32,
+    end: u32,
+}
+
+fn as_range(range: &(u32, u32)) -> RangeView {
+    RangeView {
+        start: range.0,
+        end: range.1,
+    }
+}
+
+#[derive(Dbg)]
+struct TestStruct {
+    #[dbg(debug_with = "as_range")]
+    range: (u32, u32),
+}
+
+#[derive(Dbg)]
+enum TestEnum {
+    Variant(#[dbg(debug_with = "as_range")] (u32, u32)),
+}
+
+#[test]
+fn test_debug_with() {
+    let s = TestStruct { range: (1, 5) };
+    assert_eq!(
+        format!("{:?}", s),
+        "TestStruct { range: RangeView { start: 1, end: 5 } }"
+    );
+    assert_eq!(
+        format!("{:#?}", s),
+        "TestStruct {\n    range: RangeView {\n        start: 1,\n        end: 5,\n    },\n}"
+    );
+    assert_eq!(
+        format!("{:?}", TestEnum::Variant((2, 3))),
+        "Variant(RangeView { start: 2, end: 3 })"
+    );
+}
@@ -0,0 +1,82 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+#[dbg(dedup_fields)]
+struct Margin {
+    top: u32,
+    right: u32,
+    bottom: u32,
+    left: u32,
+}
+
+#[derive(Dbg)]
+#[dbg(dedup_fields)]
+#[allow(dead_code)]
+struct Mixed {
+    a: u8,
+    #[dbg(fmt = "{}")]
+    b: &'static str,
+    #[dbg(skip)]
+    c: u8,
+    d: u32,
+}
+
+#[derive(Dbg)]
+#[dbg(dedup_fields, non_exhaustive)]
+enum Shape {
+    Rect { width: u32, height: u32 },
+    Empty {},
+    Tuple(u32, u32),
+}
+
+#[test]
+fn test_dedup_fields() {
+    let margin = Margin {
+        top: 4,
+        right: 4,
+        bottom: 4,
+        left: 8,
+    };
+    assert_eq!(
+        format!("{:?}", margin),
+        "Margin { top, right, bottom: 4, left: 8 }"
+    );
+
+    let margin = Margin {
+        top: 1,
+        right: 2,
+        bottom: 2,
+        left: 1,
+    };
+    assert_eq!(
+        format!("{:?}", margin),
+        "Margin { top: 1, right, bottom: 2, left: 1 }"
+    );
+}
+
+#[test]
+fn test_dedup_fields_printed_value() {
+    let mixed = Mixed {
+        a: 1,
+        b: "1",
+        c: 1,
+        d: 1,
+    };
+    assert_eq!(format!("{:?}", mixed), "Mixed { a, b, d: 1, .. }");
+}
+
+#[test]
+fn test_dedup_fields_enum() {
+    assert_eq!(
+        format!(
+            "{:?}",
+            Shape::Rect {
+                width: 2,
+                height: 2
+            }
+        ),
+        "Rect { width, height: 2, .. }"
+    );
+    assert_eq!(format!("{:?}", Shape::Empty {}), "Empty { .. }");
+    assert_eq!(format!("{:?}", Shape::Tuple(1, 1)), "Tuple(1, 1, ..)");
+}
@@ -0,0 +1,54 @@
+use std::ops::Deref;
+
+use derive_debug::Dbg;
+
+#[derive(Debug)]
+struct Name(String);
+
+impl Deref for Name {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+#[derive(Dbg)]
+struct User {
+    #[dbg(deref)]
+    name: Name,
+    plain: Name,
+}
+
+#[derive(Dbg)]
+enum Value {
+    Text(#[dbg(deref)] Name),
+    Boxed {
+        #[dbg(deref, alias = "inner")]
+        value: Box<u32>,
+    },
+}
+
+#[test]
+fn test_deref() {
+    let user = User {
+        name: Name("abc".into()),
+        plain: Name("def".into()),
+    };
+    assert_eq!(
+        format!("{:?}", user),
+        "User { name: \"abc\", plain: Name(\"def\") }"
+    );
+}
+
+#[test]
+fn test_deref_variant() {
+    assert_eq!(
+        format!("{:?}", Value::Text(Name("abc".into()))),
+        "Text(\"abc\")"
+    );
+    assert_eq!(
+        format!("{:?}", Value::Boxed { value: Box::new(1) }),
+        "Boxed { inner: 1 }"
+    );
+}
@@ -0,0 +1,68 @@
+use derive_debug::Dbg;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+#[derive(Dbg)]
+#[dbg(detect_cycles)]
+struct Node {
+    value: u32,
+    next: Option<Rc<RefCell<Node>>>,
+}
+
+#[derive(Dbg)]
+#[dbg(detect_cycles)]
+struct Wrapper {
+    inner: Inner,
+}
+
+#[derive(Dbg)]
+#[dbg(detect_cycles)]
+struct Inner(u32);
+
+#[test]
+fn test_cycle() {
+    let a = Rc::new(RefCell::new(Node {
+        value: 1,
+        next: None,
+    }));
+    let b = Rc::new(RefCell::new(Node {
+        value: 2,
+        next: Some(a.clone()),
+    }));
+    a.borrow_mut().next = Some(b.clone());
+
+    assert_eq!(
+        format!("{:?}", a.borrow()),
+        "Node { value: 1, next: Some(RefCell { value: Node { value: 2, next: Some(RefCell { value: <cycle> }) } }) }"
+    );
+    // printing again works, the visited values are reset after printing
+    assert_eq!(
+        format!("{:?}", b.borrow()),
+        "Node { value: 2, next: Some(RefCell { value: Node { value: 1, next: Some(RefCell { value: <cycle> }) } }) }"
+    );
+
+    a.borrow_mut().next = None;
+}
+
+#[test]
+fn test_same_address() {
+    let w = Wrapper { inner: Inner(42) };
+    assert_eq!(format!("{:?}", w), "Wrapper { inner: Inner(42) }");
+}
+
+#[test]
+fn test_no_cycle() {
+    let a = Rc::new(RefCell::new(Node {
+        value: 1,
+        next: None,
+    }));
+    let b = Node {
+        value: 2,
+        next: Some(a.clone()),
+    };
+    // the same value printed twice side by side is no cycle
+    assert_eq!(
+        format!("{:?}", (&b, &b)),
+        "(Node { value: 2, next: Some(RefCell { value: Node { value: 1, next: None } }) }, Node { value: 2, next: Some(RefCell { value: Node { value: 1, next: None } }) })"
+    );
+}
@@ -0,0 +1,51 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+#[dbg(discriminant)]
+enum Plain {
+    A,
+    B = 5,
+    C,
+    #[dbg(alias = "Dee")]
+    D = -1,
+    #[dbg(skip)]
+    E = 10,
+}
+
+#[derive(Dbg)]
+#[dbg(discriminant)]
+#[repr(u8)]
+enum WithFields {
+    Nop = 1,
+    Jump(u16) = 7,
+    #[dbg(alias = "Ret")]
+    Return = 200,
+}
+
+#[derive(Dbg)]
+#[dbg(discriminant, variant_style = "qualified")]
+#[repr(i16)]
+enum Qualified {
+    Low = -300,
+}
+
+#[test]
+fn test_discriminant() {
+    assert_eq!(format!("{:?}", Plain::A), "A(0)");
+    assert_eq!(format!("{:?}", Plain::B), "B(5)");
+    assert_eq!(format!("{:?}", Plain::C), "C(6)");
+    assert_eq!(format!("{:?}", Plain::D), "Dee(-1)");
+    assert_eq!(format!("{:?}", Plain::E), "E");
+}
+
+#[test]
+fn test_discriminant_with_fields() {
+    assert_eq!(format!("{:?}", WithFields::Nop), "Nop(1)");
+    assert_eq!(format!("{:?}", WithFields::Jump(3)), "Jump(3)");
+    assert_eq!(format!("{:?}", WithFields::Return), "Ret(200)");
+}
+
+#[test]
+fn test_discriminant_repr() {
+    assert_eq!(format!("{:?}", Qualified::Low), "Qualified::Low(-300)");
+}
@@ -0,0 +1,67 @@
+use derive_debug::Dbg;
+use std::fmt;
+
+struct Version(u32, u32);
+
+impl fmt::Debug for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Version")
+            .field(&self.0)
+            .field(&self.1)
+            .finish()
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "v{}.{}", self.0, self.1)
+    }
+}
+
+#[derive(Dbg)]
+struct TestStruct {
+    #[dbg(display)]
+    version: Version,
+    debug: Version,
+    #[dbg(display)]
+    name: String,
+}
+
+#[derive(Dbg)]
+enum TestEnum {
+    Tuple(#[dbg(display)] Version),
+    Struct {
+        #[dbg(display)]
+        version: Version,
+    },
+}
+
+#[test]
+fn test_struct() {
+    let foo = TestStruct {
+        version: Version(1, 2),
+        debug: Version(3, 4),
+        name: "abc".to_string(),
+    };
+    assert_eq!(
+        format!("{:?}", foo),
+        "TestStruct { version: v1.2, debug: Version(3, 4), name: abc }"
+    );
+}
+
+#[test]
+fn test_enum() {
+    assert_eq!(
+        format!("{:?}", TestEnum::Tuple(Version(0, 1))),
+        "Tuple(v0.1)"
+    );
+    assert_eq!(
+        format!(
+            "{:?}",
+            TestEnum::Struct {
+                version: Version(2, 0)
+            }
+        ),
+        "Struct { version: v2.0 }"
+    );
+}
@@ -0,0 +1,85 @@
+use derive_debug::Dbg;
+use std::collections::{BTreeMap, BTreeSet};
+
+fn fmt_hex(value: &u32) -> String {
+    format!("{:#x}", value)
+}
+
+fn fmt_upper(value: &&'static str) -> String {
+    value.to_uppercase()
+}
+
+fn fmt_entry((key, value): (&char, &u8)) -> String {
+    format!("{}={}", key, value)
+}
+
+#[derive(Dbg)]
+struct TestStruct {
+    #[dbg(each_formatter = "fmt_hex")]
+    values: Vec<u32>,
+    #[dbg(each_formatter = "fmt_hex")]
+    array: [u32; 2],
+    #[dbg(each_formatter = "fmt_upper")]
+    names: BTreeSet<&'static str>,
+    #[dbg(each_formatter = "fmt_entry")]
+    map: BTreeMap<char, u8>,
+}
+
+#[derive(Dbg)]
+enum TestEnum {
+    Tuple(#[dbg(each_formatter = "fmt_hex")] Vec<u32>),
+    Struct {
+        #[dbg(each_formatter = "fmt_upper")]
+        names: BTreeSet<&'static str>,
+    },
+}
+
+#[test]
+fn test_struct() {
+    let foo = TestStruct {
+        values: vec![10, 255],
+        array: [1, 16],
+        names: ["b", "a"].into_iter().collect(),
+        map: [('x', 1), ('y', 2)].into_iter().collect(),
+    };
+    assert_eq!(
+        format!("{:?}", foo),
+        "TestStruct { values: [0xa, 0xff], array: [0x1, 0x10], names: [A, B], map: [x=1, y=2] }"
+    );
+}
+
+#[test]
+fn test_empty() {
+    let foo = TestStruct {
+        values: vec![],
+        array: [0, 0],
+        names: BTreeSet::new(),
+        map: BTreeMap::new(),
+    };
+    assert_eq!(
+        format!("{:?}", foo),
+        "TestStruct { values: [], array: [0x0, 0x0], names: [], map: [] }"
+    );
+}
+
+#[test]
+fn test_enum() {
+    assert_eq!(format!("{:?}", TestEnum::Tuple(vec![12])), "Tuple([0xc])");
+    assert_eq!(
+        format!(
+            "{:?}",
+            TestEnum::Struct {
+                names: ["c"].into_iter().collect()
+            }
+        ),
+        "Struct { names: [C] }"
+    );
+}
+
+#[test]
+fn test_alternate() {
+    assert_eq!(
+        format!("{:#?}", TestEnum::Tuple(vec![1, 2])),
+        "Tuple(\n    [\n        0x1,\n        0x2,\n    ],\n)"
+    );
+}
@@ -34,6 +34,7 @@ TestStructAlias {
     placeholder_field: ...,
     alias_placeholder_field_alias: abc,
     fmt_field: 0x00AB,
+    ..
 }
 "#
     );
@@ -140,3 +141,25 @@ fn test_aliased_struct_variant() {
     let foo = TestEnum::AliasedStructVariant { a: 0, b: 1 };
     assert_eq!(format!("{:?}", foo), "AliasVariant { a: 0, b: 1 }");
 }
+
+#[derive(Dbg)]
+#[dbg(alias = "Foo{bar}")]
+struct TestBraceAliasStruct;
+
+#[derive(Dbg)]
+enum TestBraceAliasEnum {
+    #[dbg(alias = "Foo{bar}")]
+    UnitVariant,
+    #[dbg(skip, alias = "{}")]
+    SkippedUnitVariant,
+}
+
+#[test]
+fn test_brace_alias() {
+    assert_eq!(format!("{:?}", TestBraceAliasStruct), "Foo{bar}");
+    assert_eq!(format!("{:?}", TestBraceAliasEnum::UnitVariant), "Foo{bar}");
+    assert_eq!(
+        format!("{:?}", TestBraceAliasEnum::SkippedUnitVariant),
+        "{}"
+    );
+}
@@ -0,0 +1,44 @@
+use derive_debug::Dbg;
+
+#[derive(Clone, Copy)]
+#[repr(u8)]
+#[allow(dead_code)]
+enum Mode {
+    Read = 1,
+    Write = 2,
+    Execute = 4,
+}
+
+#[derive(Clone, Copy)]
+#[allow(dead_code)]
+enum Implicit {
+    First,
+    Second,
+}
+
+#[derive(Dbg)]
+struct TestStruct {
+    #[dbg(enum_index)]
+    mode: Mode,
+    #[dbg(enum_index)]
+    implicit: Implicit,
+}
+
+#[derive(Dbg)]
+enum TestEnum {
+    Tuple(#[dbg(enum_index)] Mode),
+}
+
+#[test]
+fn test_struct() {
+    let foo = TestStruct {
+        mode: Mode::Execute,
+        implicit: Implicit::Second,
+    };
+    assert_eq!(format!("{:?}", foo), "TestStruct { mode: 4, implicit: 1 }");
+}
+
+#[test]
+fn test_enum() {
+    assert_eq!(format!("{:?}", TestEnum::Tuple(Mode::Write)), "Tuple(2)");
+}
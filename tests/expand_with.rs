@@ -0,0 +1,69 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+struct Pixel {
+    x: u32,
+    #[dbg(expand_with = "rgba")]
+    color: u32,
+    #[dbg(skip)]
+    _y: u32,
+}
+
+fn rgba(color: &u32) -> [(&'static str, String); 4] {
+    let [r, g, b, a] = color.to_be_bytes();
+    [
+        ("r", r.to_string()),
+        ("g", g.to_string()),
+        ("b", b.to_string()),
+        ("a", a.to_string()),
+    ]
+}
+
+fn is_transparent(color: &u32) -> bool {
+    color & 0xFF == 0
+}
+
+#[derive(Dbg)]
+enum Shape {
+    Dot {
+        #[dbg(expand_with = "rgba", skip_if = "is_transparent")]
+        color: u32,
+    },
+}
+
+#[derive(Dbg)]
+#[dbg(best_effort)]
+struct BestEffort {
+    #[dbg(expand_with = "rgba")]
+    color: u32,
+}
+
+#[test]
+fn test_expand_with() {
+    let pixel = Pixel {
+        x: 3,
+        color: 0x11223344,
+        _y: 0,
+    };
+    assert_eq!(
+        format!("{:?}", pixel),
+        "Pixel { x: 3, r: 17, g: 34, b: 51, a: 68, .. }"
+    );
+}
+
+#[test]
+fn test_expand_with_skip_if() {
+    assert_eq!(format!("{:?}", Shape::Dot { color: 0xFF000000 }), "Dot");
+    assert_eq!(
+        format!("{:?}", Shape::Dot { color: 0xFF0000FF }),
+        "Dot { r: 255, g: 0, b: 0, a: 255 }"
+    );
+}
+
+#[test]
+fn test_expand_with_best_effort() {
+    assert_eq!(
+        format!("{:?}", BestEffort { color: 0x01020304 }),
+        "BestEffort { r: 1, g: 2, b: 3, a: 4 }"
+    );
+}
@@ -0,0 +1,45 @@
+#![cfg(feature = "testing")]
+
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+#[allow(dead_code)]
+struct Foo {
+    field_a: bool,
+    #[dbg(skip)]
+    field_b: u32,
+    #[dbg(skip_if = "Option::is_none")]
+    field_c: Option<u32>,
+}
+
+#[derive(Dbg)]
+#[allow(dead_code)]
+struct Tuple<T>(T, #[dbg(skip)] u32)
+where
+    T: Clone + std::fmt::Debug;
+
+#[derive(Dbg)]
+struct Unit;
+
+#[derive(Dbg)]
+#[dbg(opt_in)]
+#[allow(dead_code)]
+struct OptIn {
+    #[dbg(show)]
+    a: u32,
+    b: u32,
+}
+
+#[derive(Dbg)]
+#[allow(dead_code)]
+enum Bar {
+    A(u32),
+}
+
+#[test]
+fn test_field_count() {
+    assert_eq!(Foo::DEBUG_FIELD_COUNT, 2);
+    assert_eq!(Tuple::<u32>::DEBUG_FIELD_COUNT, 1);
+    assert_eq!(Unit::DEBUG_FIELD_COUNT, 0);
+    assert_eq!(OptIn::DEBUG_FIELD_COUNT, 1);
+}
@@ -0,0 +1,63 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+struct TestStruct {
+    name: &'static str,
+    value: u32,
+    #[dbg(order = 0)]
+    id: u32,
+}
+
+#[derive(Dbg)]
+#[allow(dead_code)]
+struct TestMany {
+    #[dbg(order = 10)]
+    a: u32,
+    b: u32,
+    #[dbg(order = 1, alias = "renamed")]
+    c: u32,
+    #[dbg(skip, order = 0)]
+    d: u32,
+}
+
+#[derive(Dbg)]
+#[allow(dead_code)]
+enum TestEnum {
+    Struct {
+        a: u32,
+        #[dbg(order = 0, fmt = "{:#x}")]
+        b: u32,
+        #[dbg(skip)]
+        c: u32,
+    },
+}
+
+#[test]
+fn test_struct() {
+    let s = TestStruct {
+        name: "foo",
+        value: 1,
+        id: 7,
+    };
+    assert_eq!(
+        format!("{:?}", s),
+        r#"TestStruct { id: 7, name: "foo", value: 1 }"#
+    );
+
+    let s = TestMany {
+        a: 1,
+        b: 2,
+        c: 3,
+        d: 4,
+    };
+    assert_eq!(
+        format!("{:?}", s),
+        "TestMany { renamed: 3, b: 2, a: 1, .. }"
+    );
+}
+
+#[test]
+fn test_enum() {
+    let e = TestEnum::Struct { a: 1, b: 2, c: 3 };
+    assert_eq!(format!("{:?}", e), "Struct { b: 0x2, a: 1, .. }");
+}
@@ -0,0 +1,33 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+#[dbg(fit_width)]
+struct TestStruct {
+    a: u32,
+    b: u32,
+}
+
+#[derive(Dbg)]
+#[dbg(fit_width)]
+enum TestEnum {
+    Variant(u32),
+    Unit,
+}
+
+#[test]
+fn test_fit_width() {
+    let s = TestStruct { a: 1, b: 2 };
+    assert_eq!(format!("{:?}", s), "TestStruct { a: 1, b: 2 }");
+    assert_eq!(format!("{:30?}", s), "TestStruct { a: 1, b: 2 }     ");
+    assert_eq!(format!("{:>30?}", s), "     TestStruct { a: 1, b: 2 }");
+    assert_eq!(format!("{:-^29?}", s), "--TestStruct { a: 1, b: 2 }--");
+    assert_eq!(format!("{:10?}", s), "TestStruct");
+    assert_eq!(format!("{:#30?}", s), "TestStruct { a: 1, b: 2 }     ");
+    assert_eq!(format!("{:20?}", s).chars().count(), 20);
+}
+
+#[test]
+fn test_fit_width_enum() {
+    assert_eq!(format!("{:12?}", TestEnum::Variant(42)), "Variant(42) ");
+    assert_eq!(format!("{:3?}", TestEnum::Unit), "Uni");
+}
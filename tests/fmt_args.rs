@@ -0,0 +1,63 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+struct Rect {
+    #[dbg(alias = "size", fmt = "{}/{}", self.width, self.height)]
+    width: u32,
+    #[dbg(skip)]
+    height: u32,
+    #[dbg(fmt = "{:.1} ({}x)", self.scale * 100.0, scale)]
+    scale: f32,
+}
+
+#[derive(Dbg)]
+struct Bare {
+    #[dbg(fmt = "{}..{}", start, end)]
+    start: u32,
+    #[dbg(skip)]
+    end: u32,
+}
+
+#[derive(Dbg)]
+#[allow(dead_code)]
+enum Shape {
+    Rect {
+        #[dbg(alias = "size", fmt = "{}/{}", width, height)]
+        width: u32,
+        #[dbg(skip)]
+        height: u32,
+    },
+    Circle(#[dbg(fmt = "r={}", 2 + 1)] u32),
+}
+
+#[test]
+fn test_fmt_args_struct() {
+    let rect = Rect {
+        width: 3,
+        height: 4,
+        scale: 1.5,
+    };
+    assert_eq!(
+        format!("{:?}", rect),
+        "Rect { size: 3/4, scale: 150.0 (1.5x), .. }"
+    );
+    assert_eq!(
+        format!("{:?}", Bare { start: 1, end: 5 }),
+        "Bare { start: 1..5, .. }"
+    );
+}
+
+#[test]
+fn test_fmt_args_variant() {
+    assert_eq!(
+        format!(
+            "{:?}",
+            Shape::Rect {
+                width: 3,
+                height: 4
+            }
+        ),
+        "Rect { size: 3/4, .. }"
+    );
+    assert_eq!(format!("{:?}", Shape::Circle(1)), "Circle(r=3)");
+}
@@ -0,0 +1,37 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+struct TestStruct {
+    #[dbg(fn_ptr)]
+    callback: fn(u32) -> u32,
+}
+
+#[derive(Dbg)]
+enum TestEnum {
+    Tuple(#[dbg(fn_ptr)] fn(u32) -> u32),
+}
+
+fn double(v: u32) -> u32 {
+    v * 2
+}
+
+#[test]
+fn test_struct() {
+    let foo = TestStruct { callback: double };
+    let address = format!("{:p}", foo.callback as *const ());
+    assert!(address.starts_with("0x"));
+    assert_eq!(
+        format!("{:?}", foo),
+        format!("TestStruct {{ callback: {} }}", address)
+    );
+}
+
+#[test]
+fn test_enum() {
+    let callback: fn(u32) -> u32 = double;
+    let foo = TestEnum::Tuple(callback);
+    assert_eq!(
+        format!("{:?}", foo),
+        format!("Tuple({:p})", callback as *const ())
+    );
+}
@@ -0,0 +1,66 @@
+use derive_debug::Dbg;
+use std::fmt::Display;
+
+trait Describe {
+    type Display: Display;
+
+    fn describe(&self) -> Self::Display;
+}
+
+impl Describe for u32 {
+    type Display = String;
+
+    fn describe(&self) -> String {
+        format!("#{}", self)
+    }
+}
+
+struct Celsius(f32);
+
+impl Describe for Celsius {
+    type Display = &'static str;
+
+    fn describe(&self) -> &'static str {
+        if self.0 < 0.0 {
+            "freezing"
+        } else {
+            "fine"
+        }
+    }
+}
+
+fn describe<T: Describe>(value: &T) -> <T as Describe>::Display {
+    value.describe()
+}
+
+#[derive(Dbg)]
+struct TestStruct {
+    #[dbg(formatter = "describe")]
+    id: u32,
+    #[dbg(formatter = "Describe::describe")]
+    temp: Celsius,
+}
+
+#[derive(Dbg)]
+struct Generic<T: Describe> {
+    #[dbg(formatter = "describe::<T>")]
+    value: T,
+}
+
+#[test]
+fn test_formatter_assoc_type() {
+    let s = TestStruct {
+        id: 7,
+        temp: Celsius(-3.0),
+    };
+    assert_eq!(format!("{:?}", s), "TestStruct { id: #7, temp: freezing }");
+    assert_eq!(
+        format!(
+            "{:?}",
+            Generic {
+                value: Celsius(20.0)
+            }
+        ),
+        "Generic { value: fine }"
+    );
+}
@@ -0,0 +1,81 @@
+use std::fmt::{Formatter, Result};
+
+use derive_debug::Dbg;
+
+/// Implements neither `Debug` nor `Display`.
+struct Secret(u32);
+
+trait Describe {
+    fn describe(&self) -> String;
+}
+
+impl Describe for Secret {
+    fn describe(&self) -> String {
+        format!("secret #{}", self.0)
+    }
+}
+
+fn describe<T: Describe>(value: &T) -> String {
+    value.describe()
+}
+
+fn write_secret(value: &Secret, f: &mut Formatter) -> Result {
+    write!(f, "<{}>", value.0)
+}
+
+#[derive(Dbg)]
+#[allow(dead_code)]
+struct TestStruct {
+    #[dbg(formatter = "describe")]
+    a: Secret,
+    #[dbg(with = "write_secret")]
+    b: Secret,
+    #[dbg(placeholder = "...")]
+    c: Secret,
+    #[dbg(skip)]
+    d: Secret,
+}
+
+#[derive(Dbg)]
+#[allow(dead_code)]
+struct Generic<T: Describe> {
+    #[dbg(formatter = "describe")]
+    value: T,
+    #[dbg(skip)]
+    other: T,
+}
+
+#[derive(Dbg)]
+enum TestEnum<T: Describe> {
+    Tuple(
+        #[dbg(formatter = "describe")] T,
+        #[dbg(placeholder = "?")] T,
+    ),
+}
+
+#[test]
+fn test_struct() {
+    let s = TestStruct {
+        a: Secret(1),
+        b: Secret(2),
+        c: Secret(3),
+        d: Secret(4),
+    };
+    assert_eq!(
+        format!("{:?}", s),
+        "TestStruct { a: secret #1, b: <2>, c: ..., .. }"
+    );
+}
+
+#[test]
+fn test_generic() {
+    let g = Generic {
+        value: Secret(5),
+        other: Secret(6),
+    };
+    assert_eq!(format!("{:?}", g), "Generic { value: secret #5, .. }");
+    assert_eq!(
+        format!("{:?}", TestEnum::Tuple(Secret(7), Secret(8))),
+        "Tuple(secret #7, ?)"
+    );
+}
@@ -0,0 +1,40 @@
+use std::fmt;
+
+use derive_debug::Dbg;
+
+fn fmt_hex(v: &u32, f: &mut fmt::Formatter) -> fmt::Result {
+    if f.alternate() {
+        write!(f, "{:#x}", v)
+    } else {
+        write!(f, "{:x}", v)
+    }
+}
+
+fn fmt_padded(v: &str, f: &mut fmt::Formatter) -> fmt::Result {
+    f.pad(v)
+}
+
+#[derive(Dbg)]
+struct Foo {
+    #[dbg(formatter_fmt = "fmt_hex")]
+    value: u32,
+}
+
+#[derive(Dbg)]
+enum Bar {
+    Tuple(#[dbg(formatter_fmt = "fmt_padded")] &'static str),
+}
+
+#[test]
+fn test_formatter_fmt() {
+    assert_eq!(format!("{:?}", Foo { value: 255 }), "Foo { value: ff }");
+    assert_eq!(
+        format!("{:#?}", Foo { value: 255 }),
+        "Foo {\n    value: 0xff,\n}"
+    );
+}
+
+#[test]
+fn test_formatter_fmt_flags() {
+    assert_eq!(format!("{:5?}", Bar::Tuple("a")), "Tuple(a    )");
+}
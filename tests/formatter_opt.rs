@@ -0,0 +1,57 @@
+use derive_debug::Dbg;
+
+fn port_name(port: &u16) -> Option<&'static str> {
+    match port {
+        80 => Some("http"),
+        443 => Some("https"),
+        _ => None,
+    }
+}
+
+fn non_empty(v: &[u32]) -> Option<String> {
+    if v.is_empty() {
+        None
+    } else {
+        Some(format!("{} elements", v.len()))
+    }
+}
+
+#[derive(Dbg)]
+struct TestStruct {
+    #[dbg(formatter_opt = "port_name")]
+    port: u16,
+    #[dbg(formatter_opt = "non_empty")]
+    values: Vec<u32>,
+}
+
+#[derive(Dbg)]
+enum TestEnum {
+    Tuple(#[dbg(formatter_opt = "port_name")] u16),
+}
+
+#[test]
+fn test_struct() {
+    let foo = TestStruct {
+        port: 443,
+        values: vec![1, 2],
+    };
+    assert_eq!(
+        format!("{:?}", foo),
+        "TestStruct { port: https, values: 2 elements }"
+    );
+
+    let foo = TestStruct {
+        port: 8080,
+        values: vec![],
+    };
+    assert_eq!(
+        format!("{:?}", foo),
+        "TestStruct { port: 8080, values: [] }"
+    );
+}
+
+#[test]
+fn test_enum() {
+    assert_eq!(format!("{:?}", TestEnum::Tuple(80)), "Tuple(http)");
+    assert_eq!(format!("{:?}", TestEnum::Tuple(81)), "Tuple(81)");
+}
@@ -0,0 +1,24 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+struct TestStruct {
+    #[dbg(formatter_owned = "char::to_uppercase")]
+    c: char,
+    #[dbg(formatter_owned = "u32::count_ones")]
+    bits: u32,
+}
+
+#[derive(Dbg)]
+enum TestEnum {
+    Variant(#[dbg(formatter_owned = "char::to_uppercase")] char),
+}
+
+#[test]
+fn test_formatter_owned() {
+    let s = TestStruct {
+        c: 'a',
+        bits: 0b1011,
+    };
+    assert_eq!(format!("{:?}", s), "TestStruct { c: A, bits: 3 }");
+    assert_eq!(format!("{:?}", TestEnum::Variant('b')), "Variant(B)");
+}
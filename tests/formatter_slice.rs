@@ -0,0 +1,41 @@
+use derive_debug::Dbg;
+
+fn fmt_sum(values: &[u32]) -> u32 {
+    values.iter().sum()
+}
+
+fn fmt_str(value: &str) -> usize {
+    value.len()
+}
+
+#[derive(Dbg)]
+struct TestStruct {
+    #[dbg(formatter = "fmt_sum")]
+    values: Vec<u32>,
+    #[dbg(formatter = "fmt_sum")]
+    array: [u32; 3],
+    #[dbg(formatter = "fmt_str")]
+    name: String,
+}
+
+#[derive(Dbg)]
+enum TestEnum {
+    Variant(#[dbg(formatter = "fmt_sum")] Vec<u32>),
+}
+
+#[test]
+fn test_formatter_slice() {
+    let s = TestStruct {
+        values: vec![1, 2, 3],
+        array: [4, 5, 6],
+        name: "abc".to_string(),
+    };
+    assert_eq!(
+        format!("{:?}", s),
+        "TestStruct { values: 6, array: 15, name: 3 }"
+    );
+    assert_eq!(
+        format!("{:?}", TestEnum::Variant(vec![7, 8])),
+        "Variant(15)"
+    );
+}
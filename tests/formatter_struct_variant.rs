@@ -0,0 +1,61 @@
+use derive_debug::Dbg;
+
+fn f(v: &u32) -> String {
+    format!("<{}>", v)
+}
+
+fn double(v: u32) -> u32 {
+    v * 2
+}
+
+fn wrap(v: &str) -> Option<&str> {
+    Some(v)
+}
+
+#[derive(Dbg)]
+enum E {
+    V {
+        #[dbg(formatter = "f")]
+        a: u32,
+        b: u32,
+    },
+    W {
+        first: u32,
+        #[dbg(formatter = "f", alias = "second")]
+        b: u32,
+        #[dbg(formatter_owned = "double")]
+        c: u32,
+        #[dbg(debug_with = "wrap")]
+        d: String,
+    },
+    // a field with the same name as the formatter argument of `fmt`
+    X {
+        #[dbg(formatter = "f")]
+        f: u32,
+        builder: u32,
+    },
+}
+
+#[test]
+fn test_formatter_struct_variant() {
+    assert_eq!(format!("{:?}", E::V { a: 1, b: 2 }), "V { a: <1>, b: 2 }");
+
+    let w = E::W {
+        first: 1,
+        b: 2,
+        c: 3,
+        d: "x".to_owned(),
+    };
+    assert_eq!(
+        format!("{:?}", w),
+        "W { first: 1, second: <2>, c: 6, d: Some(\"x\") }"
+    );
+    assert_eq!(
+        format!("{:?}", E::X { f: 1, builder: 2 }),
+        "X { f: <1>, builder: 2 }"
+    );
+    assert_eq!(
+        format!("{:#?}", E::V { a: 1, b: 2 }),
+        "V {\n    a: <1>,\n    b: 2,\n}"
+    );
+}
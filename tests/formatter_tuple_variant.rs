@@ -0,0 +1,41 @@
+use derive_debug::Dbg;
+use std::any::type_name;
+
+fn hex(v: &u32) -> String {
+    format!("{:#x}", v)
+}
+
+// prints the type the formatter receives, to make sure no extra reference is added
+fn arg_type<T>(_: T) -> &'static str {
+    type_name::<T>()
+}
+
+#[derive(Dbg)]
+enum TestEnum {
+    Tuple(#[dbg(formatter = "hex")] u32, u32),
+    Second(u32, #[dbg(formatter = "hex")] u32),
+    Type(#[dbg(formatter = "arg_type")] u32),
+    Named {
+        #[dbg(formatter = "arg_type")]
+        a: u32,
+    },
+}
+
+#[derive(Dbg)]
+struct TestStruct(#[dbg(formatter = "arg_type")] u32);
+
+#[test]
+fn test_formatter_tuple_variant() {
+    assert_eq!(format!("{:?}", TestEnum::Tuple(255, 1)), "Tuple(0xff, 1)");
+    assert_eq!(format!("{:?}", TestEnum::Second(1, 16)), "Second(1, 0x10)");
+}
+
+#[test]
+fn test_formatter_argument_type() {
+    assert_eq!(format!("{:?}", TestEnum::Type(1)), "Type(&u32)");
+    assert_eq!(
+        format!("{:?}", TestEnum::Named { a: 1 }),
+        "Named { a: &u32 }"
+    );
+    assert_eq!(format!("{:?}", TestStruct(1)), "TestStruct(&u32)");
+}
@@ -0,0 +1,91 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+#[dbg(group("server", [group("addr", ["host", "port"]), "timeout"]))]
+struct Config {
+    name: &'static str,
+    host: &'static str,
+    port: u16,
+    timeout: u32,
+}
+
+#[derive(Dbg)]
+#[dbg(group("pos", ["x", "y"]), group("size", ["h", "w"]))]
+#[dbg(order = "w")]
+struct Rect {
+    x: i32,
+    y: i32,
+    w: u32,
+    #[dbg(skip_if = "is_zero")]
+    h: u32,
+}
+
+fn is_zero(v: &u32) -> bool {
+    *v == 0
+}
+
+#[derive(Dbg)]
+#[dbg(group("a", [group("b", [group("c", ["value"])])]))]
+#[allow(dead_code)]
+struct Deep {
+    #[dbg(alias = "v", fmt = "{:#x}")]
+    value: u32,
+    #[dbg(skip)]
+    hidden: u32,
+}
+
+#[test]
+fn test_nested_groups() {
+    let config = Config {
+        name: "app",
+        host: "localhost",
+        port: 80,
+        timeout: 30,
+    };
+    assert_eq!(
+        format!("{:?}", config),
+        "Config { name: \"app\", server: server { addr: addr { host: \"localhost\", port: 80 }, timeout: 30 } }"
+    );
+    assert_eq!(
+        format!("{:#?}", config),
+        r#"Config {
+    name: "app",
+    server: server {
+        addr: addr {
+            host: "localhost",
+            port: 80,
+        },
+        timeout: 30,
+    },
+}"#
+    );
+
+    let deep = Deep {
+        value: 255,
+        hidden: 1,
+    };
+    assert_eq!(
+        format!("{:?}", deep),
+        "Deep { a: a { b: b { c: c { v: 0xff } } }, .. }"
+    );
+}
+
+#[test]
+fn test_group_options() {
+    let rect = Rect {
+        x: 1,
+        y: 2,
+        w: 3,
+        h: 0,
+    };
+    assert_eq!(
+        format!("{:?}", rect),
+        "Rect { size: size { w: 3 }, pos: pos { x: 1, y: 2 } }"
+    );
+
+    let rect = Rect { h: 4, ..rect };
+    assert_eq!(
+        format!("{:?}", rect),
+        "Rect { size: size { h: 4, w: 3 }, pos: pos { x: 1, y: 2 } }"
+    );
+}
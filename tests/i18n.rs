@@ -0,0 +1,59 @@
+use derive_debug::Dbg;
+
+fn translate(key: &str) -> &str {
+    match key {
+        "Size" => "Größe",
+        "width" => "Breite",
+        "height" => "Höhe",
+        "Shape::Circle" => "Form::Kreis",
+        "radius" => "r",
+        _ => key,
+    }
+}
+
+#[derive(Dbg)]
+#[dbg(i18n = "translate")]
+struct Size {
+    width: u32,
+    #[dbg(alias = "height")]
+    h: u32,
+    depth: u32,
+}
+
+#[derive(Dbg)]
+#[dbg(i18n = "translate", variant_style = "qualified")]
+enum Shape {
+    Circle { radius: u32 },
+    Square(u32),
+}
+
+#[derive(Dbg)]
+#[dbg(i18n = "translate", rename_all = "snake_case")]
+struct Tuple(#[dbg(alias = "width")] u32, u32);
+
+#[test]
+fn test_i18n() {
+    let size = Size {
+        width: 1,
+        h: 2,
+        depth: 3,
+    };
+    assert_eq!(
+        format!("{:?}", size),
+        "Größe { Breite: 1, Höhe: 2, depth: 3 }"
+    );
+}
+
+#[test]
+fn test_i18n_enum() {
+    assert_eq!(
+        format!("{:?}", Shape::Circle { radius: 1 }),
+        "Form::Kreis { r: 1 }"
+    );
+    assert_eq!(format!("{:?}", Shape::Square(2)), "Shape::Square(2)");
+}
+
+#[test]
+fn test_i18n_tuple() {
+    assert_eq!(format!("{:?}", Tuple(1, 2)), "Tuple { Breite: 1, 1: 2 }");
+}
@@ -0,0 +1,58 @@
+#![cfg(feature = "serde")]
+
+use std::collections::HashMap;
+
+use derive_debug::Dbg;
+use serde::Serialize;
+
+#[derive(Serialize)]
+struct Point {
+    x: u32,
+    y: u32,
+}
+
+#[derive(Dbg)]
+struct TestStruct {
+    #[dbg(json_compact)]
+    point: Point,
+    #[dbg(json_compact)]
+    invalid: HashMap<(u32, u32), u32>,
+}
+
+#[derive(Dbg)]
+enum TestEnum {
+    Tuple(#[dbg(json_compact)] Vec<Point>),
+}
+
+#[test]
+fn test_struct() {
+    let foo = TestStruct {
+        point: Point { x: 1, y: 2 },
+        invalid: HashMap::new(),
+    };
+    assert_eq!(
+        format!("{:?}", foo),
+        r#"TestStruct { point: {"x":1,"y":2}, invalid: {} }"#
+    );
+}
+
+#[test]
+fn test_serialization_error() {
+    let foo = TestStruct {
+        point: Point { x: 1, y: 2 },
+        invalid: HashMap::from([((1, 2), 3)]),
+    };
+    assert_eq!(
+        format!("{:?}", foo),
+        r#"TestStruct { point: {"x":1,"y":2}, invalid: <serialization error> }"#
+    );
+}
+
+#[test]
+fn test_enum() {
+    let foo = TestEnum::Tuple(vec![Point { x: 1, y: 2 }, Point { x: 3, y: 4 }]);
+    assert_eq!(
+        format!("{:?}", foo),
+        r#"Tuple([{"x":1,"y":2},{"x":3,"y":4}])"#
+    );
+}
@@ -0,0 +1,56 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+struct Inner {
+    a: u32,
+}
+
+#[derive(Dbg)]
+struct TestStruct {
+    #[dbg(label = "role=primary")]
+    inner: Inner,
+    #[dbg(label = "hex", fmt = "{:#X}")]
+    value: u32,
+}
+
+#[derive(Dbg)]
+enum TestEnum {
+    Tuple(#[dbg(label = "first")] u32, u32),
+}
+
+#[test]
+fn test_struct() {
+    let foo = TestStruct {
+        inner: Inner { a: 42 },
+        value: 255,
+    };
+    assert_eq!(
+        format!("{:?}", foo),
+        "TestStruct { inner: [role=primary] Inner { a: 42 }, value: [hex] 0xFF }"
+    );
+}
+
+#[test]
+fn test_struct_pretty() {
+    let foo = TestStruct {
+        inner: Inner { a: 42 },
+        value: 255,
+    };
+    assert_eq!(
+        format!("\n{:#?}\n", foo),
+        r#"
+TestStruct {
+    inner: [role=primary] Inner {
+        a: 42,
+    },
+    value: [hex] 0xFF,
+}
+"#
+    );
+}
+
+#[test]
+fn test_enum() {
+    let foo = TestEnum::Tuple(1, 2);
+    assert_eq!(format!("{:?}", foo), "Tuple([first] 1, 2)");
+}
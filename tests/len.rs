@@ -0,0 +1,40 @@
+use std::collections::HashMap;
+
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+struct Foo {
+    #[dbg(len)]
+    data: Vec<u32>,
+    #[dbg(len = "{} entries", alias = "map")]
+    lookup: HashMap<u32, u32>,
+    #[dbg(len = "{} bytes")]
+    name: &'static str,
+}
+
+#[derive(Dbg)]
+struct Tuple(#[dbg(len)] [u8; 4]);
+
+#[derive(Dbg)]
+enum Bar {
+    Items(#[dbg(len = "len={}")] Vec<String>),
+}
+
+#[test]
+fn test_len() {
+    let foo = Foo {
+        data: vec![1, 2, 3],
+        lookup: HashMap::new(),
+        name: "äb",
+    };
+    assert_eq!(
+        format!("{:?}", foo),
+        "Foo { data: 3 items, map: 0 entries, name: 3 bytes }"
+    );
+
+    assert_eq!(format!("{:?}", Tuple([0; 4])), "Tuple(4 items)");
+    assert_eq!(
+        format!("{:?}", Bar::Items(vec!["a".to_owned()])),
+        "Items(len=1)"
+    );
+}
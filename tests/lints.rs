@@ -0,0 +1,41 @@
+//! The generated code must not trigger lints in crates denying them.
+#![deny(unused_qualifications, clippy::all)]
+
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+#[dbg(best_effort, non_exhaustive)]
+#[allow(dead_code)]
+struct Foo {
+    #[dbg(skip_if_none)]
+    a: Option<u32>,
+    #[dbg(fmt = "{:#x}")]
+    b: u32,
+    #[dbg(placeholder = "...")]
+    c: Vec<u8>,
+}
+
+#[derive(Dbg)]
+#[dbg(discriminant)]
+#[repr(u8)]
+#[allow(dead_code)]
+enum Bar {
+    A,
+    B {
+        x: u32,
+        y: u32,
+    },
+    #[dbg(catch_all)]
+    C(Box<Foo>),
+}
+
+#[test]
+fn test_lints() {
+    let foo = Foo {
+        a: None,
+        b: 16,
+        c: vec![],
+    };
+    assert_eq!(format!("{:?}", foo), "Foo { b: 0x10, c: ..., .. }");
+    assert_eq!(format!("{:?}", Bar::A), "A(0)");
+}
@@ -0,0 +1,57 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+#[dbg(logfmt)]
+#[allow(dead_code)]
+struct Request {
+    id: u32,
+    #[dbg(fmt = "{}")]
+    path: String,
+    #[dbg(fmt = "{}", alias = "user_agent")]
+    agent: String,
+    #[dbg(skip)]
+    secret: u32,
+    #[dbg(fmt = "{}")]
+    empty: String,
+    tags: Vec<u32>,
+    name: &'static str,
+    spaced: &'static str,
+    escaped: &'static str,
+}
+
+#[derive(Dbg)]
+#[dbg(logfmt)]
+#[allow(dead_code)]
+struct Tuple(u32, #[dbg(skip)] u32, u32);
+
+#[derive(Dbg)]
+#[dbg(logfmt)]
+#[allow(dead_code)]
+enum Event {
+    Start { id: u32 },
+    Data(#[dbg(skip)] u32, &'static str),
+    Stop,
+}
+
+#[test]
+fn test_logfmt() {
+    let r = Request {
+        id: 42,
+        path: "/index.html".to_string(),
+        agent: "curl 8.0".to_string(),
+        secret: 1,
+        empty: String::new(),
+        tags: vec![1],
+        name: "a",
+        spaced: "a b",
+        escaped: "a=\"b\"",
+    };
+    assert_eq!(
+        format!("{:?}", r),
+        r#"Request id=42 path=/index.html user_agent="curl 8.0" empty="" tags=[1] name=a spaced="a b" escaped="a=\"b\"" .."#
+    );
+    assert_eq!(format!("{:?}", Tuple(1, 2, 3)), "Tuple 0=1 2=3 ..");
+    assert_eq!(format!("{:?}", Event::Start { id: 1 }), "Start id=1");
+    assert_eq!(format!("{:?}", Event::Data(1, "x")), "Data 1=x ..");
+    assert_eq!(format!("{:?}", Event::Stop), "Stop");
+}
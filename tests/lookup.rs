@@ -0,0 +1,37 @@
+use derive_debug::Dbg;
+
+const NAMES: [&str; 4] = ["r0", "r1", "r2", "r3"];
+
+mod regs {
+    pub static NAMES: &[&str] = &["a", "b"];
+}
+
+#[derive(Dbg)]
+struct TestStruct {
+    #[dbg(lookup = "NAMES")]
+    a: u8,
+    #[dbg(lookup = "regs::NAMES")]
+    b: u32,
+}
+
+#[derive(Dbg)]
+enum TestEnum {
+    Variant(#[dbg(lookup = "NAMES")] usize),
+}
+
+#[test]
+fn test_lookup() {
+    assert_eq!(
+        format!("{:?}", TestStruct { a: 2, b: 1 }),
+        "TestStruct { a: r2, b: b }"
+    );
+    assert_eq!(
+        format!("{:?}", TestStruct { a: 4, b: 2 }),
+        "TestStruct { a: <out of range>, b: <out of range> }"
+    );
+    assert_eq!(format!("{:?}", TestEnum::Variant(3)), "Variant(r3)");
+    assert_eq!(
+        format!("{:?}", TestEnum::Variant(usize::MAX)),
+        "Variant(<out of range>)"
+    );
+}
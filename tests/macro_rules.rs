@@ -0,0 +1,43 @@
+mod helpers {
+    pub fn fmt_hex(v: &u32) -> String {
+        format!("{:#x}", v)
+    }
+
+    pub fn write_hex(v: &u32, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{:#x}", v)
+    }
+}
+
+macro_rules! define_struct {
+    ($name:ident) => {
+        #[derive(derive_debug::Dbg)]
+        struct $name {
+            #[dbg(formatter = "crate::helpers::fmt_hex")]
+            a: u32,
+            #[dbg(with = "crate::helpers::write_hex")]
+            b: u32,
+        }
+    };
+}
+
+macro_rules! define_struct_with_formatter {
+    ($name:ident, $formatter:literal) => {
+        #[derive(derive_debug::Dbg)]
+        struct $name(#[dbg(formatter = $formatter)] u32);
+    };
+}
+
+define_struct!(FromMacro);
+define_struct_with_formatter!(FormatterFromMacro, "helpers::fmt_hex");
+
+#[test]
+fn test_macro_rules() {
+    assert_eq!(
+        format!("{:?}", FromMacro { a: 255, b: 16 }),
+        "FromMacro { a: 0xff, b: 0x10 }"
+    );
+    assert_eq!(
+        format!("{:?}", FormatterFromMacro(255)),
+        "FormatterFromMacro(0xff)"
+    );
+}
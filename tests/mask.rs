@@ -0,0 +1,30 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+struct Packed {
+    #[dbg(mask = "0xF0", shift = 4)]
+    high: u8,
+    #[dbg(mask = 0x0F, fmt = "{:#x}")]
+    low: u8,
+    #[dbg(shift = 8)]
+    upper: u16,
+}
+
+#[derive(Dbg)]
+enum TestEnum {
+    Variant(#[dbg(mask = "0b1100", shift = 2)] u32),
+}
+
+#[test]
+fn test_mask() {
+    let p = Packed {
+        high: 0xAB,
+        low: 0xAB,
+        upper: 0x1234,
+    };
+    assert_eq!(
+        format!("{:?}", p),
+        "Packed { high: 10, low: 0xb, upper: 18 }"
+    );
+    assert_eq!(format!("{:?}", TestEnum::Variant(0b0110)), "Variant(1)");
+}
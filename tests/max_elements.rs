@@ -0,0 +1,49 @@
+use derive_debug::Dbg;
+use std::collections::BTreeSet;
+
+#[derive(Dbg)]
+#[dbg(max_elements = 2)]
+struct Capped {
+    #[dbg(collection)]
+    a: Vec<u32>,
+    #[dbg(collection)]
+    b: BTreeSet<u32>,
+    #[dbg(collection)]
+    c: [u32; 2],
+    d: Vec<u32>,
+}
+
+#[derive(Dbg)]
+struct Uncapped {
+    #[dbg(collection)]
+    a: Vec<u32>,
+}
+
+#[derive(Dbg)]
+#[dbg(max_elements = 1)]
+enum CappedEnum {
+    Variant(#[dbg(collection)] Vec<u32>),
+}
+
+#[test]
+fn test_max_elements() {
+    let s = Capped {
+        a: vec![1, 2, 3, 4],
+        b: [5, 6, 7].into_iter().collect(),
+        c: [8, 9],
+        d: vec![1, 2, 3],
+    };
+    assert_eq!(
+        format!("{:?}", s),
+        "Capped { a: [1, 2, ..], b: [5, 6, ..], c: [8, 9], d: [1, 2, 3] }"
+    );
+
+    assert_eq!(
+        format!("{:?}", Uncapped { a: vec![1, 2, 3] }),
+        "Uncapped { a: [1, 2, 3] }"
+    );
+    assert_eq!(
+        format!("{:?}", CappedEnum::Variant(vec![1, 2])),
+        "Variant([1, ..])"
+    );
+}
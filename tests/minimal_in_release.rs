@@ -0,0 +1,41 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+#[dbg(minimal_in_release)]
+struct Foo {
+    a: u32,
+    #[dbg(alias = "bee")]
+    b: bool,
+}
+
+#[derive(Dbg)]
+#[dbg(minimal_in_release, variant_style = "qualified")]
+enum Bar {
+    Unit,
+    Tuple(u32),
+    #[dbg(alias = "Named")]
+    Struct {
+        a: u32,
+    },
+}
+
+#[test]
+#[cfg(debug_assertions)]
+fn test_minimal_in_release_debug() {
+    assert_eq!(
+        format!("{:?}", Foo { a: 1, b: true }),
+        "Foo { a: 1, bee: true }"
+    );
+    assert_eq!(format!("{:?}", Bar::Unit), "Bar::Unit");
+    assert_eq!(format!("{:?}", Bar::Tuple(1)), "Bar::Tuple(1)");
+    assert_eq!(format!("{:?}", Bar::Struct { a: 1 }), "Bar::Named { a: 1 }");
+}
+
+#[test]
+#[cfg(not(debug_assertions))]
+fn test_minimal_in_release_release() {
+    assert_eq!(format!("{:?}", Foo { a: 1, b: true }), "Foo");
+    assert_eq!(format!("{:?}", Bar::Unit), "Bar::Unit");
+    assert_eq!(format!("{:?}", Bar::Tuple(1)), "Bar::Tuple");
+    assert_eq!(format!("{:?}", Bar::Struct { a: 1 }), "Bar::Named");
+}
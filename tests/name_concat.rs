@@ -0,0 +1,55 @@
+use derive_debug::Dbg;
+
+const MODULE: &str = "net";
+const NAME: &str = "Socket";
+
+mod consts {
+    pub const VERSION: &str = "v2";
+}
+
+#[derive(Dbg)]
+#[dbg(name_concat = "MODULE, \"::\", NAME")]
+struct Consts {
+    port: u16,
+}
+
+#[derive(Dbg)]
+#[dbg(name_concat = "NAME, '_', consts::VERSION, 2, true")]
+struct Mixed(u8);
+
+#[derive(Dbg)]
+#[dbg(name_concat = "\"Lit\", 1, \"Only\"")]
+struct Literals;
+
+#[derive(Dbg)]
+#[dbg(name_concat = "NAME", alias_prefix = "<", alias_suffix = ">")]
+struct Affixes<T: std::fmt::Debug> {
+    value: T,
+}
+
+#[derive(Dbg)]
+#[dbg(name_concat = "MODULE", crate_prefixed)]
+struct Prefixed {}
+
+#[test]
+fn test_consts() {
+    assert_eq!(
+        format!("{:?}", Consts { port: 80 }),
+        "net::Socket { port: 80 }"
+    );
+    assert_eq!(format!("{:?}", Mixed(1)), "Socket_v22true(1)");
+}
+
+#[test]
+fn test_literals() {
+    assert_eq!(format!("{:?}", Literals), "Lit1Only");
+}
+
+#[test]
+fn test_affixes() {
+    assert_eq!(
+        format!("{:?}", Affixes { value: "a" }),
+        "<Socket> { value: \"a\" }"
+    );
+    assert_eq!(format!("{:?}", Prefixed {}), "derive-debug::net");
+}
@@ -0,0 +1,31 @@
+use derive_debug::Dbg;
+
+const OPCODE_NAMES: [&str; 3] = ["NOP", "LOAD", "STORE"];
+
+#[derive(Dbg)]
+#[dbg(names_from = "OPCODE_NAMES")]
+enum Opcode {
+    Nop,
+    Load,
+    Store,
+    Halt,
+}
+
+#[derive(Dbg)]
+#[dbg(names_from = "OPCODE_NAMES")]
+#[repr(u8)]
+enum Sparse {
+    Store = 2,
+    Other = 10,
+}
+
+#[test]
+fn test_names_from() {
+    assert_eq!(format!("{:?}", Opcode::Nop), "NOP");
+    assert_eq!(format!("{:?}", Opcode::Load), "LOAD");
+    assert_eq!(format!("{:?}", Opcode::Store), "STORE");
+    assert_eq!(format!("{:?}", Opcode::Halt), "Halt");
+
+    assert_eq!(format!("{:?}", Sparse::Store), "STORE");
+    assert_eq!(format!("{:?}", Sparse::Other), "Other");
+}
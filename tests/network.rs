@@ -0,0 +1,33 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+struct Interface {
+    #[dbg(ip)]
+    addr: [u8; 4],
+    #[dbg(ip)]
+    mask: u32,
+    #[dbg(mac)]
+    mac: [u8; 6],
+}
+
+#[derive(Dbg)]
+enum Packet {
+    Arp(#[dbg(mac)] [u8; 6], #[dbg(ip)] [u8; 4]),
+}
+
+#[test]
+fn test_ip_and_mac() {
+    let i = Interface {
+        addr: [192, 168, 0, 1],
+        mask: 0xFFFFFF00,
+        mac: [0x00, 0x1a, 0x2b, 0x3c, 0x4d, 0x5e],
+    };
+    assert_eq!(
+        format!("{:?}", i),
+        "Interface { addr: 192.168.0.1, mask: 255.255.255.0, mac: 00:1a:2b:3c:4d:5e }"
+    );
+    assert_eq!(
+        format!("{:?}", Packet::Arp([0xff; 6], [10, 0, 0, 1])),
+        "Arp(ff:ff:ff:ff:ff:ff, 10.0.0.1)"
+    );
+}
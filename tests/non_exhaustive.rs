@@ -0,0 +1,191 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+#[allow(dead_code)]
+enum TestEnum {
+    Unit,
+    Exhaustive {
+        a: u32,
+    },
+    #[dbg(non_exhaustive)]
+    NonExhaustive {
+        a: u32,
+        #[dbg(skip)]
+        b: u32,
+    },
+    #[dbg(non_exhaustive)]
+    NonExhaustiveTuple(u32),
+    #[dbg(non_exhaustive)]
+    NonExhaustiveUnit,
+}
+
+#[derive(Dbg)]
+#[dbg(non_exhaustive)]
+enum TestNonExhaustiveEnum {
+    Named { a: u32 },
+    Tuple(u32),
+}
+
+#[derive(Dbg)]
+#[dbg(non_exhaustive)]
+struct TestStruct {
+    a: u32,
+}
+
+#[derive(Dbg)]
+#[dbg(non_exhaustive)]
+struct TestTuple(u32);
+
+#[derive(Dbg)]
+#[dbg(non_exhaustive)]
+struct TestUnit;
+
+#[derive(Dbg)]
+#[dbg(non_exhaustive, best_effort)]
+struct TestBestEffort {
+    a: u32,
+}
+
+#[test]
+fn test_variants() {
+    assert_eq!(format!("{:?}", TestEnum::Unit), "Unit");
+    assert_eq!(
+        format!("{:?}", TestEnum::Exhaustive { a: 1 }),
+        "Exhaustive { a: 1 }"
+    );
+    assert_eq!(
+        format!("{:?}", TestEnum::NonExhaustive { a: 1, b: 2 }),
+        "NonExhaustive { a: 1, .. }"
+    );
+    assert_eq!(
+        format!("{:?}", TestEnum::NonExhaustiveTuple(1)),
+        "NonExhaustiveTuple(1, ..)"
+    );
+    assert_eq!(
+        format!("{:?}", TestEnum::NonExhaustiveUnit),
+        "NonExhaustiveUnit"
+    );
+}
+
+#[test]
+fn test_container_default() {
+    assert_eq!(
+        format!("{:?}", TestNonExhaustiveEnum::Named { a: 1 }),
+        "Named { a: 1, .. }"
+    );
+    assert_eq!(
+        format!("{:?}", TestNonExhaustiveEnum::Tuple(1)),
+        "Tuple(1, ..)"
+    );
+}
+
+#[test]
+fn test_struct() {
+    assert_eq!(
+        format!("{:?}", TestStruct { a: 1 }),
+        "TestStruct { a: 1, .. }"
+    );
+    assert_eq!(format!("{:?}", TestTuple(1)), "TestTuple(1, ..)");
+    assert_eq!(format!("{:?}", TestUnit), "TestUnit { .. }");
+    assert_eq!(
+        format!("{:?}", TestBestEffort { a: 1 }),
+        "TestBestEffort { a: 1, .. }"
+    );
+}
+
+mod skip_matrix {
+    use derive_debug::Dbg;
+
+    #[derive(Dbg)]
+    struct Plain {
+        a: u32,
+    }
+
+    #[derive(Dbg)]
+    #[allow(dead_code)]
+    struct Skipped {
+        a: u32,
+        #[dbg(skip)]
+        b: u32,
+    }
+
+    #[derive(Dbg)]
+    #[dbg(non_exhaustive)]
+    struct NonExhaustive {
+        a: u32,
+    }
+
+    #[derive(Dbg)]
+    #[dbg(non_exhaustive)]
+    #[allow(dead_code)]
+    struct NonExhaustiveSkipped {
+        a: u32,
+        #[dbg(skip)]
+        b: u32,
+    }
+
+    #[derive(Dbg)]
+    struct SkippedIf {
+        a: u32,
+        #[dbg(skip_if = "is_zero")]
+        b: u32,
+    }
+
+    fn is_zero(v: &u32) -> bool {
+        *v == 0
+    }
+
+    #[derive(Dbg)]
+    #[allow(dead_code)]
+    struct SkippedTuple(u32, #[dbg(skip)] u32);
+
+    #[derive(Dbg)]
+    #[allow(dead_code)]
+    enum SkippedVariants {
+        Skipped {
+            a: u32,
+            #[dbg(skip)]
+            b: u32,
+        },
+        Plain {
+            a: u32,
+        },
+        Tuple(#[dbg(skip)] u32),
+    }
+
+    #[test]
+    fn test_skip_matrix() {
+        assert_eq!(format!("{:?}", Plain { a: 1 }), "Plain { a: 1 }");
+        assert_eq!(
+            format!("{:?}", Skipped { a: 1, b: 2 }),
+            "Skipped { a: 1, .. }"
+        );
+        assert_eq!(
+            format!("{:?}", NonExhaustive { a: 1 }),
+            "NonExhaustive { a: 1, .. }"
+        );
+        assert_eq!(
+            format!("{:?}", NonExhaustiveSkipped { a: 1, b: 2 }),
+            "NonExhaustiveSkipped { a: 1, .. }"
+        );
+    }
+
+    #[test]
+    fn test_skip_kinds() {
+        // fields omitted at runtime do not mark the output as non-exhaustive
+        assert_eq!(
+            format!("{:?}", SkippedIf { a: 1, b: 0 }),
+            "SkippedIf { a: 1 }"
+        );
+        assert_eq!(format!("{:?}", SkippedTuple(1, 2)), "SkippedTuple(1, ..)");
+        assert_eq!(
+            format!("{:?}", SkippedVariants::Skipped { a: 1, b: 2 }),
+            "Skipped { a: 1, .. }"
+        );
+        assert_eq!(
+            format!("{:?}", SkippedVariants::Plain { a: 1 }),
+            "Plain { a: 1 }"
+        );
+        assert_eq!(format!("{:?}", SkippedVariants::Tuple(1)), "Tuple(..)");
+    }
+}
@@ -0,0 +1,69 @@
+use derive_debug::Dbg;
+
+const PARTIAL: bool = true;
+
+#[derive(Dbg)]
+#[dbg(non_exhaustive_if = "N < 4")]
+struct Header<const N: usize> {
+    version: u32,
+}
+
+#[derive(Dbg)]
+#[dbg(non_exhaustive_if = "PARTIAL")]
+struct Tuple(u32);
+
+#[derive(Dbg)]
+#[dbg(non_exhaustive_if = "self.is_partial()")]
+enum Message {
+    Data { len: u32 },
+}
+
+impl Message {
+    fn is_partial(&self) -> bool {
+        matches!(self, Message::Data { len } if *len > 2)
+    }
+}
+
+#[derive(Dbg)]
+#[dbg(non_exhaustive_if = "N < 4", best_effort)]
+struct BestEffort<const N: usize> {
+    a: u32,
+}
+
+#[derive(Dbg)]
+#[dbg(non_exhaustive_if = "N < 4", logfmt)]
+struct Logfmt<const N: usize> {
+    a: u32,
+}
+
+#[test]
+fn test_non_exhaustive_if() {
+    assert_eq!(
+        format!("{:?}", Header::<2> { version: 1 }),
+        "Header { version: 1, .. }"
+    );
+    assert_eq!(
+        format!("{:?}", Header::<4> { version: 1 }),
+        "Header { version: 1 }"
+    );
+    assert_eq!(format!("{:?}", Tuple(1)), "Tuple(1, ..)");
+    assert_eq!(
+        format!("{:?}", Message::Data { len: 3 }),
+        "Data { len: 3, .. }"
+    );
+    assert_eq!(format!("{:?}", Message::Data { len: 1 }), "Data { len: 1 }");
+}
+
+#[test]
+fn test_non_exhaustive_if_manual_output() {
+    assert_eq!(
+        format!("{:?}", BestEffort::<1> { a: 1 }),
+        "BestEffort { a: 1, .. }"
+    );
+    assert_eq!(
+        format!("{:?}", BestEffort::<5> { a: 1 }),
+        "BestEffort { a: 1 }"
+    );
+    assert_eq!(format!("{:?}", Logfmt::<1> { a: 1 }), "Logfmt a=1 ..");
+    assert_eq!(format!("{:?}", Logfmt::<5> { a: 1 }), "Logfmt a=1");
+}
@@ -0,0 +1,46 @@
+use derive_debug::Dbg;
+use std::marker::PhantomPinned;
+
+struct NotDebug;
+
+#[derive(Dbg)]
+struct TestStruct {
+    #[dbg(opaque)]
+    callback: Box<dyn Fn(u32) -> u32>,
+    #[dbg(opaque)]
+    value: NotDebug,
+    #[dbg(opaque)]
+    pin: PhantomPinned,
+    a: u32,
+}
+
+#[derive(Dbg)]
+struct TestTuple(#[dbg(opaque)] *const u8, u32);
+
+#[derive(Dbg)]
+enum TestEnum {
+    Variant(#[dbg(opaque)] NotDebug),
+}
+
+#[test]
+fn test_opaque() {
+    let s = TestStruct {
+        callback: Box::new(|x| x + 1),
+        value: NotDebug,
+        pin: PhantomPinned,
+        a: 42,
+    };
+    assert_eq!(
+        format!("{:?}", s),
+        "TestStruct { callback: <opaque>, value: <opaque>, pin: <opaque>, a: 42 }"
+    );
+
+    assert_eq!(
+        format!("{:?}", TestTuple(std::ptr::null(), 1)),
+        "TestTuple(<opaque>, 1)"
+    );
+    assert_eq!(
+        format!("{:?}", TestEnum::Variant(NotDebug)),
+        "Variant(<opaque>)"
+    );
+}
@@ -0,0 +1,67 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+#[dbg(opt_in)]
+#[allow(dead_code)]
+struct Foo {
+    #[dbg(show)]
+    id: u32,
+    cache: Vec<u8>,
+    #[dbg(show, alias = "len", fmt = "{:#x}")]
+    length: usize,
+    buffer: Vec<u8>,
+}
+
+#[derive(Dbg)]
+#[dbg(opt_in)]
+struct AllShown(#[dbg(show)] u32, #[dbg(show)] u32);
+
+#[derive(Dbg)]
+#[dbg(opt_in)]
+#[allow(dead_code)]
+struct NoneShown(u32, String);
+
+struct NotDebug;
+
+#[derive(Dbg)]
+#[dbg(opt_in)]
+#[allow(dead_code)]
+enum Event {
+    Click {
+        #[dbg(show)]
+        x: i32,
+        #[dbg(show)]
+        y: i32,
+        handler: NotDebug,
+    },
+    Key(#[dbg(show)] char),
+    Raw(NotDebug),
+}
+
+#[test]
+fn test_opt_in_struct() {
+    let foo = Foo {
+        id: 1,
+        cache: vec![1, 2],
+        length: 16,
+        buffer: vec![],
+    };
+    assert_eq!(format!("{:?}", foo), "Foo { id: 1, len: 0x10, .. }");
+    assert_eq!(format!("{:?}", AllShown(1, 2)), "AllShown(1, 2)");
+    assert_eq!(
+        format!("{:?}", NoneShown(1, String::new())),
+        "NoneShown(..)"
+    );
+}
+
+#[test]
+fn test_opt_in_enum() {
+    let click = Event::Click {
+        x: 1,
+        y: 2,
+        handler: NotDebug,
+    };
+    assert_eq!(format!("{:?}", click), "Click { x: 1, y: 2, .. }");
+    assert_eq!(format!("{:?}", Event::Key('a')), "Key('a')");
+    assert_eq!(format!("{:?}", Event::Raw(NotDebug)), "Raw(..)");
+}
@@ -0,0 +1,46 @@
+use derive_debug::Dbg;
+
+fn celsius(v: &f32) -> String {
+    format!("{:.1}°C", v)
+}
+
+fn upper(v: &str) -> String {
+    v.to_uppercase()
+}
+
+#[derive(Dbg)]
+struct Reading {
+    #[dbg(option_formatter = "celsius", none = "∅")]
+    temperature: Option<f32>,
+    #[dbg(option_formatter = "celsius")]
+    fallback: Option<f32>,
+}
+
+#[derive(Dbg)]
+enum Label {
+    Named(#[dbg(none = "<unnamed>", option_formatter = "upper")] Option<&'static str>),
+}
+
+#[test]
+fn test_option_formatter() {
+    let reading = Reading {
+        temperature: Some(21.54),
+        fallback: Some(-3.0),
+    };
+    assert_eq!(
+        format!("{:?}", reading),
+        "Reading { temperature: 21.5°C, fallback: -3.0°C }"
+    );
+
+    let reading = Reading {
+        temperature: None,
+        fallback: None,
+    };
+    assert_eq!(
+        format!("{:?}", reading),
+        "Reading { temperature: ∅, fallback: None }"
+    );
+
+    assert_eq!(format!("{:?}", Label::Named(Some("abc"))), "Named(ABC)");
+    assert_eq!(format!("{:?}", Label::Named(None)), "Named(<unnamed>)");
+}
@@ -0,0 +1,165 @@
+#![deny(warnings)]
+
+//! Combinations of the basic field options on every kind of item.
+//! The generated code has to compile without warnings, skipped and placeholder fields
+//! are only allowed to be unused because nothing else reads them in this test.
+
+use derive_debug::Dbg;
+
+fn double(v: &u32) -> u32 {
+    v * 2
+}
+
+#[derive(Dbg)]
+#[allow(dead_code)]
+struct Named {
+    plain: u32,
+    #[dbg(skip)]
+    skipped: u32,
+    #[dbg(skip, alias = "x")]
+    skipped_alias: u32,
+    #[dbg(alias = "renamed")]
+    aliased: u32,
+    #[dbg(fmt = "{:#x}")]
+    formatted: u32,
+    #[dbg(fmt = "{:#x}", alias = "fmt_alias")]
+    formatted_alias: u32,
+    #[dbg(formatter = "double")]
+    custom: u32,
+    #[dbg(formatter = "double", alias = "custom_alias")]
+    custom_alias: u32,
+    #[dbg(placeholder = "...")]
+    placeholder: u32,
+    #[dbg(placeholder = "...", alias = "placeholder_alias")]
+    placeholder_alias: u32,
+}
+
+#[derive(Dbg)]
+#[allow(dead_code)]
+struct Tuple(
+    u32,
+    #[dbg(skip)] u32,
+    #[dbg(fmt = "{:#x}")] u32,
+    #[dbg(formatter = "double")] u32,
+    #[dbg(placeholder = "...")] u32,
+);
+
+#[derive(Dbg)]
+struct Unit;
+
+#[derive(Dbg)]
+#[dbg(alias = "Renamed")]
+struct UnitAlias;
+
+#[derive(Dbg)]
+#[allow(dead_code)]
+enum Enum {
+    Named {
+        plain: u32,
+        #[dbg(skip)]
+        skipped: u32,
+        #[dbg(skip, alias = "x")]
+        skipped_alias: u32,
+        #[dbg(alias = "renamed")]
+        aliased: u32,
+        #[dbg(fmt = "{:#x}", alias = "fmt_alias")]
+        formatted: u32,
+        #[dbg(formatter = "double", alias = "custom_alias")]
+        custom: u32,
+        #[dbg(placeholder = "...")]
+        placeholder: u32,
+        #[dbg(placeholder = "...", alias = "placeholder_alias")]
+        placeholder_alias: u32,
+    },
+    Tuple(
+        u32,
+        #[dbg(skip)] u32,
+        #[dbg(fmt = "{:#x}")] u32,
+        #[dbg(formatter = "double")] u32,
+        #[dbg(placeholder = "...")] u32,
+    ),
+    Unit,
+    #[dbg(alias = "RenamedNamed")]
+    AliasNamed {
+        #[dbg(skip)]
+        a: u32,
+    },
+    #[dbg(alias = "RenamedTuple")]
+    AliasTuple(#[dbg(placeholder = "...")] u32),
+    #[dbg(alias = "RenamedUnit")]
+    AliasUnit,
+    #[dbg(skip)]
+    SkipNamed {
+        a: u32,
+    },
+    #[dbg(skip)]
+    SkipTuple(u32),
+    #[dbg(skip)]
+    SkipUnit,
+}
+
+#[test]
+fn test_named() {
+    let s = Named {
+        plain: 1,
+        skipped: 2,
+        skipped_alias: 3,
+        aliased: 4,
+        formatted: 255,
+        formatted_alias: 16,
+        custom: 5,
+        custom_alias: 6,
+        placeholder: 7,
+        placeholder_alias: 8,
+    };
+    assert_eq!(
+        format!("{:?}", s),
+        "Named { plain: 1, renamed: 4, formatted: 0xff, fmt_alias: 0x10, custom: 10, custom_alias: 12, placeholder: ..., placeholder_alias: ..., .. }"
+    );
+}
+
+#[test]
+fn test_tuple() {
+    assert_eq!(
+        format!("{:?}", Tuple(1, 2, 255, 4, 5)),
+        "Tuple(1, 0xff, 8, ..., ..)"
+    );
+}
+
+#[test]
+fn test_unit() {
+    assert_eq!(format!("{:?}", Unit), "Unit");
+    assert_eq!(format!("{:?}", UnitAlias), "Renamed");
+}
+
+#[test]
+fn test_enum() {
+    let e = Enum::Named {
+        plain: 1,
+        skipped: 2,
+        skipped_alias: 3,
+        aliased: 4,
+        formatted: 255,
+        custom: 5,
+        placeholder: 6,
+        placeholder_alias: 7,
+    };
+    assert_eq!(
+        format!("{:?}", e),
+        "Named { plain: 1, renamed: 4, fmt_alias: 0xff, custom_alias: 10, placeholder: ..., placeholder_alias: ..., .. }"
+    );
+    assert_eq!(
+        format!("{:?}", Enum::Tuple(1, 2, 255, 4, 5)),
+        "Tuple(1, 0xff, 8, ..., ..)"
+    );
+    assert_eq!(format!("{:?}", Enum::Unit), "Unit");
+    assert_eq!(
+        format!("{:?}", Enum::AliasNamed { a: 1 }),
+        "RenamedNamed { .. }"
+    );
+    assert_eq!(format!("{:?}", Enum::AliasTuple(1)), "RenamedTuple(...)");
+    assert_eq!(format!("{:?}", Enum::AliasUnit), "RenamedUnit");
+    assert_eq!(format!("{:?}", Enum::SkipNamed { a: 1 }), "SkipNamed");
+    assert_eq!(format!("{:?}", Enum::SkipTuple(1)), "SkipTuple");
+    assert_eq!(format!("{:?}", Enum::SkipUnit), "SkipUnit");
+}
@@ -0,0 +1,40 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+#[dbg(order = "c, a")]
+#[allow(dead_code)]
+struct TestStruct {
+    a: u32,
+    b: u32,
+    c: u32,
+    #[dbg(skip)]
+    d: u32,
+    e: u32,
+}
+
+#[derive(Dbg)]
+#[dbg(order = "b,a")]
+struct TestFull {
+    a: u32,
+    #[dbg(alias = "renamed")]
+    b: u32,
+}
+
+#[test]
+fn test_order() {
+    let s = TestStruct {
+        a: 1,
+        b: 2,
+        c: 3,
+        d: 4,
+        e: 5,
+    };
+    assert_eq!(
+        format!("{:?}", s),
+        "TestStruct { c: 3, a: 1, b: 2, e: 5, .. }"
+    );
+    assert_eq!(
+        format!("{:?}", TestFull { a: 1, b: 2 }),
+        "TestFull { renamed: 2, a: 1 }"
+    );
+}
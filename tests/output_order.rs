@@ -0,0 +1,57 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+#[dbg(non_exhaustive)]
+#[allow(dead_code)]
+struct TestStruct {
+    z: u32,
+    #[dbg(alias = "a")]
+    y: u32,
+    #[dbg(skip)]
+    x: u32,
+    #[dbg(placeholder = "...")]
+    w: u32,
+    #[dbg(fmt = "{:#x}")]
+    v: u32,
+    b: u32,
+}
+
+#[derive(Dbg)]
+#[allow(dead_code)]
+enum TestEnum {
+    Named {
+        z: u32,
+        #[dbg(alias = "a")]
+        y: u32,
+        #[dbg(skip)]
+        x: u32,
+        w: u32,
+    },
+}
+
+#[test]
+fn test_output_order() {
+    let s = TestStruct {
+        z: 1,
+        y: 2,
+        x: 3,
+        w: 4,
+        v: 5,
+        b: 6,
+    };
+    let expected = "TestStruct { z: 1, a: 2, w: ..., v: 0x5, b: 6, .. }";
+    for _ in 0..100 {
+        assert_eq!(format!("{:?}", s), expected);
+    }
+
+    let e = TestEnum::Named {
+        z: 1,
+        y: 2,
+        x: 3,
+        w: 4,
+    };
+    let expected = "Named { z: 1, a: 2, w: 4, .. }";
+    for _ in 0..100 {
+        assert_eq!(format!("{:?}", e), expected);
+    }
+}
@@ -0,0 +1,42 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+struct TestStruct {
+    #[dbg(fmt = "{:.*}", precision_from = "digits")]
+    value: f64,
+    digits: u8,
+}
+
+#[derive(Dbg)]
+struct TestTuple(usize, #[dbg(fmt = "{:.*}", precision_from = "0")] f32);
+
+#[derive(Dbg)]
+enum TestEnum {
+    Named {
+        #[dbg(fmt = "{:.*}", precision_from = "digits")]
+        value: f64,
+        #[dbg(skip)]
+        digits: u32,
+    },
+    Tuple(
+        #[dbg(skip)] u8,
+        #[dbg(fmt = "{:.*}", precision_from = "0")] f64,
+    ),
+}
+
+#[test]
+fn test_precision_from() {
+    let s = TestStruct {
+        value: 1.23456,
+        digits: 2,
+    };
+    assert_eq!(format!("{:?}", s), "TestStruct { value: 1.23, digits: 2 }");
+    assert_eq!(format!("{:?}", TestTuple(3, 1.5)), "TestTuple(3, 1.500)");
+
+    let e = TestEnum::Named {
+        value: 2.34567,
+        digits: 3,
+    };
+    assert_eq!(format!("{:?}", e), "Named { value: 2.346, .. }");
+    assert_eq!(format!("{:?}", TestEnum::Tuple(1, 2.25)), "Tuple(2.2, ..)");
+}
@@ -0,0 +1,77 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+#[dbg(preset = "Compact")]
+struct Compact {
+    #[dbg(collection)]
+    data: Vec<u32>,
+}
+
+#[derive(Dbg)]
+#[dbg(max_elements = 1, preset = "Compact")]
+struct CompactOverridden {
+    #[dbg(collection)]
+    data: Vec<u32>,
+}
+
+#[derive(Dbg)]
+#[dbg(preset = "Verbose")]
+struct Verbose {
+    a: u32,
+}
+
+#[derive(Dbg)]
+#[dbg(preset = "Secure")]
+#[allow(dead_code)]
+struct Login {
+    #[dbg(fmt = "{:?}")]
+    user: String,
+    password: String,
+    #[dbg(placeholder = "...")]
+    token: String,
+}
+
+#[derive(Dbg)]
+#[dbg(preset = "Secure")]
+#[allow(dead_code)]
+enum Credentials {
+    Key(String),
+}
+
+#[test]
+fn test_compact() {
+    let data = vec![1, 2, 3, 4];
+    assert_eq!(
+        format!("{:?}", Compact { data: data.clone() }),
+        "Compact { data: [1, 2, 3, ..] }"
+    );
+    assert_eq!(
+        format!("{:?}", CompactOverridden { data }),
+        "CompactOverridden { data: [1, ..] }"
+    );
+}
+
+#[test]
+fn test_verbose() {
+    assert_eq!(
+        format!("{:?}", Verbose { a: 1 }),
+        "derive-debug::Verbose { a: 1 (u32) }"
+    );
+}
+
+#[test]
+fn test_secure() {
+    let l = Login {
+        user: "admin".to_string(),
+        password: "hunter2".to_string(),
+        token: "abc".to_string(),
+    };
+    assert_eq!(
+        format!("{:?}", l),
+        r#"Login { user: "admin", password: <redacted>, token: ... }"#
+    );
+    assert_eq!(
+        format!("{:?}", Credentials::Key("abc".to_string())),
+        "Key(<redacted>)"
+    );
+}
@@ -0,0 +1,48 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+struct Radix {
+    #[dbg(hex)]
+    hex: u32,
+    #[dbg(HEX)]
+    upper: u32,
+    #[dbg(oct)]
+    oct: u32,
+    #[dbg(bin)]
+    bin: u8,
+}
+
+#[derive(Dbg)]
+struct Tuple(#[dbg(HEX)] i64);
+
+#[derive(Dbg)]
+enum Register {
+    Flags {
+        #[dbg(bin, alias = "bits")]
+        flags: u8,
+    },
+}
+
+#[test]
+fn test_radix() {
+    let value = Radix {
+        hex: 42,
+        upper: 42,
+        oct: 42,
+        bin: 42,
+    };
+    assert_eq!(
+        format!("{:?}", value),
+        "Radix { hex: 0x2a, upper: 0x2A, oct: 0o52, bin: 0b101010 }"
+    );
+    assert_eq!(
+        format!("{:#?}", value),
+        "Radix {\n    hex: 0x2a,\n    upper: 0x2A,\n    oct: 0o52,\n    bin: 0b101010,\n}"
+    );
+
+    assert_eq!(format!("{:?}", Tuple(-1)), "Tuple(0xFFFFFFFFFFFFFFFF)");
+    assert_eq!(
+        format!("{:?}", Register::Flags { flags: 5 }),
+        "Flags { bits: 0b101 }"
+    );
+}
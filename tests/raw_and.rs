@@ -0,0 +1,37 @@
+use derive_debug::Dbg;
+
+fn decode_mode(mode: &u8) -> &'static str {
+    if *mode & 1 == 1 {
+        "fast"
+    } else {
+        "slow"
+    }
+}
+
+#[derive(Dbg)]
+struct Register {
+    #[dbg(fmt = "{:#04x}", raw_and = "decode_mode")]
+    mode: u8,
+    #[dbg(raw_and = "decode_mode")]
+    other: u8,
+}
+
+#[derive(Dbg)]
+enum TestEnum {
+    Variant(#[dbg(raw_and = "decode_mode")] u8),
+}
+
+#[test]
+fn test_raw_and() {
+    assert_eq!(
+        format!(
+            "{:?}",
+            Register {
+                mode: 0x1f,
+                other: 2
+            }
+        ),
+        "Register { mode: 0x1f (fast), other: 2 (slow) }"
+    );
+    assert_eq!(format!("{:?}", TestEnum::Variant(3)), "Variant(3 (fast))");
+}
@@ -0,0 +1,50 @@
+#![allow(non_camel_case_types)]
+
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+struct TestStruct {
+    r#type: u32,
+    #[dbg(alias = "r#kind")]
+    r#match: bool,
+}
+
+#[derive(Dbg)]
+struct r#struct {
+    value: u8,
+}
+
+#[derive(Dbg)]
+enum TestEnum {
+    r#fn { r#in: u8 },
+    r#Box,
+}
+
+#[derive(Dbg)]
+#[dbg(rename_all = "PascalCase")]
+struct Renamed {
+    r#type: u32,
+}
+
+#[test]
+fn test_fields() {
+    let s = TestStruct {
+        r#type: 1,
+        r#match: true,
+    };
+    assert_eq!(format!("{:?}", s), "TestStruct { type: 1, r#kind: true }");
+    assert_eq!(
+        format!("{:?}", Renamed { r#type: 2 }),
+        "Renamed { Type: 2 }"
+    );
+}
+
+#[test]
+fn test_names() {
+    assert_eq!(
+        format!("{:?}", r#struct { value: 3 }),
+        "struct { value: 3 }"
+    );
+    assert_eq!(format!("{:?}", TestEnum::r#fn { r#in: 4 }), "fn { in: 4 }");
+    assert_eq!(format!("{:?}", TestEnum::r#Box), "Box");
+}
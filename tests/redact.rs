@@ -0,0 +1,54 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+#[allow(dead_code)]
+struct Login {
+    user: String,
+    #[dbg(redact)]
+    password: String,
+    #[dbg(redact = "<hidden>")]
+    token: String,
+}
+
+#[derive(Dbg)]
+#[allow(dead_code)]
+struct Key(u32, #[dbg(redact)] [u8; 4]);
+
+#[derive(Dbg)]
+#[allow(dead_code)]
+enum Credentials {
+    Password(#[dbg(redact)] String),
+    Token {
+        #[dbg(redact = "{}")]
+        token: String,
+    },
+}
+
+#[test]
+fn test_redact() {
+    let login = Login {
+        user: "admin".to_owned(),
+        password: "hunter2".to_owned(),
+        token: "abc".to_owned(),
+    };
+    assert_eq!(
+        format!("{:?}", login),
+        "Login { user: \"admin\", password: ***, token: <hidden> }"
+    );
+
+    assert_eq!(format!("{:?}", Key(1, [1, 2, 3, 4])), "Key(1, ***)");
+
+    assert_eq!(
+        format!("{:?}", Credentials::Password("hunter2".to_owned())),
+        "Password(***)"
+    );
+    assert_eq!(
+        format!(
+            "{:?}",
+            Credentials::Token {
+                token: "abc".to_owned()
+            }
+        ),
+        "Token { token: {} }"
+    );
+}
@@ -0,0 +1,47 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+struct Login {
+    #[dbg(redact_partial = 2)]
+    token: String,
+}
+
+#[derive(Dbg)]
+struct Key(#[dbg(redact_partial = 1)] &'static str);
+
+#[derive(Dbg)]
+struct Hidden {
+    #[dbg(redact_partial = 0)]
+    secret: String,
+}
+
+#[test]
+fn test_redact_partial() {
+    let login = Login {
+        token: "abcdxyz".to_owned(),
+    };
+    assert_eq!(format!("{:?}", login), "Login { token: ab…yz }");
+
+    assert_eq!(format!("{:?}", Key("äöüß")), "Key(ä…ß)");
+
+    let hidden = Hidden {
+        secret: "abc".to_owned(),
+    };
+    assert_eq!(format!("{:?}", hidden), "Hidden { secret: … }");
+}
+
+#[test]
+fn test_redact_partial_short() {
+    let login = Login {
+        token: "abcd".to_owned(),
+    };
+    assert_eq!(format!("{:?}", login), "Login { token: … }");
+
+    let login = Login {
+        token: String::new(),
+    };
+    assert_eq!(format!("{:?}", login), "Login { token: … }");
+
+    assert_eq!(format!("{:?}", Key("ab")), "Key(…)");
+    assert_eq!(format!("{:?}", Key("abc")), "Key(a…c)");
+}
@@ -0,0 +1,64 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+#[dbg(rename_all = "camelCase")]
+struct Foo {
+    field_a: bool,
+    #[dbg(alias = "b")]
+    field_b: u32,
+}
+
+#[derive(Dbg)]
+#[dbg(rename_all = "SCREAMING_SNAKE_CASE")]
+struct Tuple(u32);
+
+#[derive(Dbg)]
+#[dbg(rename_all = "kebab-case")]
+enum Bar {
+    FirstShape {
+        some_field: u32,
+    },
+    SecondItem(u32),
+    #[dbg(alias = "Third")]
+    ThirdValue,
+}
+
+#[derive(Dbg)]
+#[dbg(rename_all = "snake_case", variant_style = "qualified")]
+enum Qualified {
+    HttpError,
+}
+
+#[derive(Dbg)]
+#[dbg(rename_all = "PascalCase")]
+struct Pascal {
+    user_id: u32,
+}
+
+#[test]
+fn test_rename_all() {
+    let foo = Foo {
+        field_a: true,
+        field_b: 42,
+    };
+    assert_eq!(format!("{:?}", foo), "Foo { fieldA: true, b: 42 }");
+
+    assert_eq!(format!("{:?}", Tuple(1)), "Tuple(1)");
+
+    assert_eq!(
+        format!("{:?}", Bar::FirstShape { some_field: 1 }),
+        "first-shape { some-field: 1 }"
+    );
+    assert_eq!(format!("{:?}", Bar::SecondItem(2)), "second-item(2)");
+    assert_eq!(format!("{:?}", Bar::ThirdValue), "Third");
+
+    assert_eq!(
+        format!("{:?}", Qualified::HttpError),
+        "Qualified::http_error"
+    );
+
+    assert_eq!(
+        format!("{:?}", Pascal { user_id: 3 }),
+        "Pascal { UserId: 3 }"
+    );
+}
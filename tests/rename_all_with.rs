@@ -0,0 +1,69 @@
+use derive_debug::Dbg;
+
+fn shout(name: &str) -> String {
+    format!("{}!", name.to_uppercase())
+}
+
+#[derive(Dbg)]
+#[dbg(rename_all_with = "shout")]
+struct TestStruct {
+    field_a: u32,
+    #[dbg(alias = "b")]
+    field_b: u32,
+}
+
+#[derive(Dbg)]
+#[dbg(rename_all_with = "crate::shout")]
+#[allow(dead_code)]
+enum TestEnum {
+    Unit,
+    Named {
+        a: u32,
+    },
+    Tuple(u32),
+    #[dbg(alias = "Aliased")]
+    AliasedUnit,
+    #[dbg(skip)]
+    Skipped(u32),
+}
+
+#[derive(Dbg)]
+#[dbg(rename_all_with = "shout", variant_style = "qualified")]
+enum TestQualifiedEnum {
+    Unit,
+    #[dbg(alias = "Aliased")]
+    AliasedUnit,
+}
+
+#[test]
+fn test_struct() {
+    let foo = TestStruct {
+        field_a: 1,
+        field_b: 2,
+    };
+    assert_eq!(format!("{:?}", foo), "TestStruct { FIELD_A!: 1, b: 2 }");
+}
+
+#[test]
+fn test_enum() {
+    assert_eq!(format!("{:?}", TestEnum::Unit), "UNIT!");
+    assert_eq!(
+        format!("{:?}", TestEnum::Named { a: 1 }),
+        "NAMED! { A!: 1 }"
+    );
+    assert_eq!(format!("{:?}", TestEnum::Tuple(1)), "TUPLE!(1)");
+    assert_eq!(format!("{:?}", TestEnum::AliasedUnit), "Aliased");
+    assert_eq!(format!("{:?}", TestEnum::Skipped(1)), "SKIPPED!");
+}
+
+#[test]
+fn test_qualified_enum() {
+    assert_eq!(
+        format!("{:?}", TestQualifiedEnum::Unit),
+        "TestQualifiedEnum::UNIT!"
+    );
+    assert_eq!(
+        format!("{:?}", TestQualifiedEnum::AliasedUnit),
+        "TestQualifiedEnum::Aliased"
+    );
+}
@@ -0,0 +1,39 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+struct TestStruct {
+    #[dbg(rle)]
+    data: [u8; 32],
+    #[dbg(rle)]
+    names: Vec<&'static str>,
+}
+
+#[derive(Dbg)]
+enum TestEnum {
+    Tuple(#[dbg(rle)] Vec<u32>),
+}
+
+#[test]
+fn test_struct() {
+    let mut data = [0; 32];
+    data[0] = 1;
+    data[20] = 7;
+    data[21] = 7;
+    let foo = TestStruct {
+        data,
+        names: vec!["a", "b", "b"],
+    };
+    assert_eq!(
+        format!("{:?}", foo),
+        r#"TestStruct { data: [1, 0 × 19, 7 × 2, 0 × 10], names: ["a", "b" × 2] }"#
+    );
+}
+
+#[test]
+fn test_enum() {
+    assert_eq!(format!("{:?}", TestEnum::Tuple(vec![])), "Tuple([])");
+    assert_eq!(
+        format!("{:?}", TestEnum::Tuple(vec![1, 2, 3])),
+        "Tuple([1, 2, 3])"
+    );
+}
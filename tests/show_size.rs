@@ -0,0 +1,47 @@
+use std::mem::size_of;
+
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+#[dbg(show_size)]
+struct TestStruct {
+    a: u8,
+    b: u64,
+}
+
+#[derive(Dbg)]
+#[dbg(show_size = "bytes")]
+struct TestRenamed<T: std::fmt::Debug> {
+    value: T,
+}
+
+#[derive(Dbg)]
+#[dbg(show_size, version = "v1")]
+struct TestUnit;
+
+#[test]
+fn test_size() {
+    let s = TestStruct { a: 1, b: 2 };
+    assert_eq!(
+        format!("{:?}", s),
+        format!(
+            "TestStruct {{ a: 1, b: 2, __size: {} }}",
+            size_of::<TestStruct>()
+        )
+    );
+}
+
+#[test]
+fn test_renamed() {
+    assert_eq!(
+        format!("{:?}", TestRenamed { value: [0u16; 3] }),
+        format!(
+            "TestRenamed {{ value: [0, 0, 0], bytes: {} }}",
+            size_of::<TestRenamed<[u16; 3]>>()
+        )
+    );
+    assert_eq!(
+        format!("{:?}", TestUnit),
+        r#"TestUnit { _schema: "v1", __size: 0 }"#
+    );
+}
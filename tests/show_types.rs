@@ -0,0 +1,44 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+#[dbg(show_types)]
+struct TestStruct {
+    a: u32,
+    #[dbg(fmt = "{:#X}")]
+    b: u8,
+    #[dbg(skip)]
+    c: u32,
+    #[dbg(label = "label")]
+    d: Option<bool>,
+}
+
+#[derive(Dbg)]
+#[dbg(show_types)]
+enum TestEnum<T: std::fmt::Debug> {
+    Tuple(T, i8),
+    Named { a: &'static str },
+}
+
+#[test]
+fn test_struct() {
+    let foo = TestStruct {
+        a: 1,
+        b: 255,
+        c: 2,
+        d: Some(true),
+    };
+    assert_eq!(
+        format!("{:?}", foo),
+        "TestStruct { a: 1 (u32), b: 0xFF (u8), d: [label] Some(true) (core::option::Option<bool>), .. }"
+    );
+    assert_eq!(foo.c, 2);
+}
+
+#[test]
+fn test_enum() {
+    let foo = TestEnum::Tuple(1u16, -1);
+    assert_eq!(format!("{:?}", foo), "Tuple(1 (u16), -1 (i8))");
+
+    let foo = TestEnum::<u16>::Named { a: "a" };
+    assert_eq!(format!("{:?}", foo), r#"Named { a: "a" (&str) }"#);
+}
@@ -0,0 +1,38 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+#[allow(dead_code)]
+enum TestEnum {
+    Unit,
+    #[dbg(silent)]
+    SilentUnit,
+    #[dbg(silent)]
+    SilentTuple(u32),
+    #[dbg(silent, alias = "Ignored")]
+    SilentNamed {
+        a: u32,
+    },
+}
+
+#[derive(Dbg)]
+struct TestStruct {
+    a: TestEnum,
+    b: Vec<TestEnum>,
+}
+
+#[test]
+fn test_silent_variants() {
+    assert_eq!(format!("{:?}", TestEnum::Unit), "Unit");
+    assert_eq!(format!("{:?}", TestEnum::SilentUnit), "");
+    assert_eq!(format!("{:?}", TestEnum::SilentTuple(1)), "");
+    assert_eq!(format!("{:?}", TestEnum::SilentNamed { a: 1 }), "");
+}
+
+#[test]
+fn test_nested() {
+    let foo = TestStruct {
+        a: TestEnum::SilentUnit,
+        b: vec![TestEnum::Unit, TestEnum::SilentTuple(1)],
+    };
+    assert_eq!(format!("{:?}", foo), "TestStruct { a: , b: [Unit, ] }");
+}
@@ -0,0 +1,56 @@
+use std::marker::PhantomData;
+
+use derive_debug::Dbg;
+
+struct NotDebug;
+
+#[derive(Dbg)]
+#[dbg(skip_bound(T))]
+struct Foo<T> {
+    id: u32,
+    value: T,
+    marker: PhantomData<T>,
+}
+
+#[derive(Dbg)]
+#[dbg(skip_bound(T, U))]
+#[allow(dead_code)]
+enum Bar<T, U> {
+    Pair(T, U),
+    Named {
+        #[dbg(placeholder = "...")]
+        value: T,
+        other: U,
+    },
+}
+
+#[test]
+fn test_skip_bound() {
+    let foo = Foo {
+        id: 1,
+        value: NotDebug,
+        marker: PhantomData,
+    };
+    assert_eq!(
+        format!("{:?}", foo),
+        "Foo { id: 1, value: <opaque>, marker: PhantomData<skip_bound::NotDebug> }"
+    );
+}
+
+#[test]
+fn test_skip_bound_enum() {
+    assert_eq!(
+        format!("{:?}", Bar::<NotDebug, NotDebug>::Pair(NotDebug, NotDebug)),
+        "Pair(<opaque>, <opaque>)"
+    );
+    assert_eq!(
+        format!(
+            "{:?}",
+            Bar::<NotDebug, NotDebug>::Named {
+                value: NotDebug,
+                other: NotDebug
+            }
+        ),
+        "Named { value: ..., other: <opaque> }"
+    );
+}
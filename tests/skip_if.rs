@@ -0,0 +1,121 @@
+use derive_debug::Dbg;
+
+fn is_zero(v: &u32) -> bool {
+    *v == 0
+}
+
+#[derive(Dbg)]
+struct TestStruct {
+    a: u32,
+    #[dbg(skip_if = "Option::is_none")]
+    b: Option<u32>,
+    #[dbg(skip_if = "is_zero", alias = "renamed")]
+    c: u32,
+}
+
+#[derive(Dbg)]
+struct TestTuple(#[dbg(skip_if = "is_zero")] u32, u32);
+
+#[derive(Dbg)]
+enum TestEnum {
+    Named {
+        #[dbg(skip_if = "is_zero", placeholder = "...")]
+        a: u32,
+    },
+    Tuple(#[dbg(skip_if = "Vec::is_empty")] Vec<u32>),
+}
+
+#[derive(Dbg)]
+#[dbg(best_effort, non_exhaustive)]
+struct TestBestEffort {
+    #[dbg(skip_if = "is_zero")]
+    a: u32,
+    #[dbg(skip_if = "is_zero")]
+    b: u32,
+}
+
+#[derive(Dbg)]
+#[dbg(best_effort)]
+struct TestBestEffortEmpty {
+    #[dbg(skip_if = "is_zero")]
+    a: u32,
+}
+
+#[derive(Dbg)]
+#[dbg(logfmt)]
+struct TestLogfmt {
+    #[dbg(skip_if = "is_zero")]
+    a: u32,
+    b: u32,
+}
+
+#[test]
+fn test_skip_if() {
+    let s = TestStruct {
+        a: 1,
+        b: None,
+        c: 0,
+    };
+    assert_eq!(format!("{:?}", s), "TestStruct { a: 1 }");
+
+    let s = TestStruct {
+        a: 1,
+        b: Some(2),
+        c: 3,
+    };
+    assert_eq!(
+        format!("{:?}", s),
+        "TestStruct { a: 1, b: Some(2), renamed: 3 }"
+    );
+    assert_eq!(
+        format!("{:#?}", s),
+        "TestStruct {\n    a: 1,\n    b: Some(\n        2,\n    ),\n    renamed: 3,\n}"
+    );
+
+    assert_eq!(format!("{:?}", TestTuple(0, 1)), "TestTuple(1)");
+    assert_eq!(format!("{:?}", TestTuple(2, 1)), "TestTuple(2, 1)");
+}
+
+#[test]
+fn test_skip_if_enum() {
+    assert_eq!(format!("{:?}", TestEnum::Named { a: 0 }), "Named");
+    assert_eq!(
+        format!("{:?}", TestEnum::Named { a: 1 }),
+        "Named { a: ... }"
+    );
+    assert_eq!(format!("{:?}", TestEnum::Tuple(vec![])), "Tuple");
+    assert_eq!(format!("{:?}", TestEnum::Tuple(vec![1])), "Tuple([1])");
+}
+
+#[test]
+fn test_skip_if_best_effort() {
+    assert_eq!(
+        format!("{:?}", TestBestEffort { a: 0, b: 2 }),
+        "TestBestEffort { b: 2, .. }"
+    );
+    assert_eq!(
+        format!("{:?}", TestBestEffort { a: 1, b: 2 }),
+        "TestBestEffort { a: 1, b: 2, .. }"
+    );
+    assert_eq!(
+        format!("{:?}", TestBestEffort { a: 0, b: 0 }),
+        "TestBestEffort { .. }"
+    );
+    assert_eq!(
+        format!("{:?}", TestBestEffortEmpty { a: 0 }),
+        "TestBestEffortEmpty"
+    );
+    assert_eq!(
+        format!("{:?}", TestBestEffortEmpty { a: 1 }),
+        "TestBestEffortEmpty { a: 1 }"
+    );
+}
+
+#[test]
+fn test_skip_if_logfmt() {
+    assert_eq!(format!("{:?}", TestLogfmt { a: 0, b: 1 }), "TestLogfmt b=1");
+    assert_eq!(
+        format!("{:?}", TestLogfmt { a: 2, b: 1 }),
+        "TestLogfmt a=2 b=1"
+    );
+}
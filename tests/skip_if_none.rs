@@ -0,0 +1,91 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+struct Foo {
+    field_a: bool,
+    #[dbg(skip_if_none, alias = "b")]
+    field_b: Option<u32>,
+    #[dbg(skip_if_none, fmt = "{:#x}")]
+    field_c: Option<u32>,
+}
+
+#[derive(Dbg)]
+struct Tuple(#[dbg(skip_if_none)] Option<&'static str>, u32);
+
+fn is_empty(v: &Option<Vec<u32>>) -> bool {
+    v.as_ref().is_some_and(Vec::is_empty)
+}
+
+#[derive(Dbg)]
+enum Bar {
+    Variant {
+        #[dbg(skip_if_none, skip_if = "is_empty")]
+        items: Option<Vec<u32>>,
+    },
+}
+
+#[derive(Dbg)]
+#[dbg(best_effort)]
+struct BestEffort {
+    #[dbg(skip_if_none)]
+    a: Option<u32>,
+    b: u32,
+}
+
+#[test]
+fn test_skip_if_none() {
+    let foo = Foo {
+        field_a: true,
+        field_b: None,
+        field_c: None,
+    };
+    assert_eq!(format!("{:?}", foo), "Foo { field_a: true }");
+
+    let foo = Foo {
+        field_a: true,
+        field_b: Some(42),
+        field_c: Some(255),
+    };
+    assert_eq!(
+        format!("{:?}", foo),
+        "Foo { field_a: true, b: 42, field_c: 0xff }"
+    );
+
+    assert_eq!(format!("{:?}", Tuple(None, 1)), "Tuple(1)");
+    assert_eq!(format!("{:?}", Tuple(Some("a"), 1)), "Tuple(\"a\", 1)");
+}
+
+#[test]
+fn test_skip_if_none_with_skip_if() {
+    assert_eq!(format!("{:?}", Bar::Variant { items: None }), "Variant");
+    assert_eq!(
+        format!(
+            "{:?}",
+            Bar::Variant {
+                items: Some(vec![])
+            }
+        ),
+        "Variant"
+    );
+    assert_eq!(
+        format!(
+            "{:?}",
+            Bar::Variant {
+                items: Some(vec![1])
+            }
+        ),
+        "Variant { items: [1] }"
+    );
+}
+
+#[test]
+fn test_skip_if_none_best_effort() {
+    assert_eq!(
+        format!("{:?}", BestEffort { a: None, b: 1 }),
+        "BestEffort { b: 1 }"
+    );
+    assert_eq!(
+        format!("{:?}", BestEffort { a: Some(2), b: 1 }),
+        "BestEffort { a: 2, b: 1 }"
+    );
+}
@@ -0,0 +1,65 @@
+use derive_debug::Dbg;
+
+struct NotDebug;
+
+#[derive(Dbg)]
+#[allow(dead_code)]
+struct NamedStruct {
+    a: u32,
+    #[dbg(skip)]
+    b: NotDebug,
+}
+
+#[derive(Dbg)]
+struct TupleStruct(u32, #[dbg(skip)] NotDebug);
+
+#[derive(Dbg)]
+#[allow(dead_code)]
+enum Enum {
+    Named {
+        a: u32,
+        #[dbg(skip)]
+        b: NotDebug,
+    },
+    Tuple(u32, #[dbg(skip)] NotDebug),
+    #[dbg(skip)]
+    SkippedVariant(NotDebug),
+}
+
+#[derive(Dbg)]
+#[allow(dead_code)]
+struct Generic<T> {
+    a: u32,
+    #[dbg(skip)]
+    b: T,
+}
+
+#[test]
+fn test_named_struct() {
+    let foo = NamedStruct { a: 1, b: NotDebug };
+    assert_eq!(format!("{:?}", foo), "NamedStruct { a: 1, .. }");
+}
+
+#[test]
+fn test_tuple_struct() {
+    let foo = TupleStruct(1, NotDebug);
+    assert_eq!(format!("{:?}", foo), "TupleStruct(1, ..)");
+}
+
+#[test]
+fn test_enum_variants() {
+    let foo = Enum::Named { a: 1, b: NotDebug };
+    assert_eq!(format!("{:?}", foo), "Named { a: 1, .. }");
+
+    let foo = Enum::Tuple(1, NotDebug);
+    assert_eq!(format!("{:?}", foo), "Tuple(1, ..)");
+
+    let foo = Enum::SkippedVariant(NotDebug);
+    assert_eq!(format!("{:?}", foo), "SkippedVariant");
+}
+
+#[test]
+fn test_generic_struct() {
+    let foo = Generic { a: 1, b: NotDebug };
+    assert_eq!(format!("{:?}", foo), "Generic { a: 1, .. }");
+}
@@ -0,0 +1,73 @@
+use std::fmt::{self, Write};
+
+use derive_debug::Dbg;
+
+fn stream_lines(lines: &[&str], f: &mut fmt::Formatter) -> fmt::Result {
+    for line in lines {
+        f.write_str(line)?;
+        f.write_char(';')?;
+    }
+    Ok(())
+}
+
+fn stream_fail(_: &u32, f: &mut fmt::Formatter) -> fmt::Result {
+    f.write_str("partial")?;
+    Err(fmt::Error)
+}
+
+#[derive(Dbg)]
+struct Log {
+    #[dbg(stream_with = "stream_lines")]
+    lines: Vec<&'static str>,
+}
+
+#[derive(Dbg)]
+struct Failing {
+    #[dbg(stream_with = "stream_fail")]
+    a: u32,
+    b: u32,
+}
+
+/// A writer failing once more than `limit` bytes are written.
+struct LimitedWriter {
+    written: String,
+    limit: usize,
+}
+
+impl Write for LimitedWriter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if self.written.len() + s.len() > self.limit {
+            return Err(fmt::Error);
+        }
+        self.written.push_str(s);
+        Ok(())
+    }
+}
+
+#[test]
+fn test_stream_with() {
+    let log = Log {
+        lines: vec!["a", "b"],
+    };
+    assert_eq!(format!("{:?}", log), "Log { lines: a;b; }");
+}
+
+#[test]
+fn test_stream_with_error() {
+    let mut out = String::new();
+    assert!(write!(out, "{:?}", Failing { a: 1, b: 2 }).is_err());
+    assert_eq!(out, "Failing { a: partial");
+}
+
+#[test]
+fn test_stream_with_failing_writer() {
+    let log = Log {
+        lines: vec!["first", "second"],
+    };
+    let mut writer = LimitedWriter {
+        written: String::new(),
+        limit: 20,
+    };
+    assert!(write!(writer, "{:?}", log).is_err());
+    assert_eq!(writer.written, "Log { lines: first;");
+}
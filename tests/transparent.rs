@@ -0,0 +1,50 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+#[dbg(transparent)]
+struct Meters(f64);
+
+#[derive(Dbg)]
+#[dbg(transparent)]
+struct Id {
+    #[dbg(fmt = "#{}")]
+    value: u32,
+}
+
+#[derive(Dbg)]
+#[dbg(transparent)]
+#[allow(dead_code)]
+struct Wrapper {
+    inner: Vec<u32>,
+    #[dbg(skip)]
+    cache: Option<u32>,
+}
+
+#[derive(Dbg)]
+#[dbg(transparent)]
+struct Node(Option<Box<Self>>);
+
+#[derive(Dbg)]
+#[dbg(transparent)]
+#[allow(dead_code)]
+struct Formatted(#[dbg(placeholder = "<self>")] Box<Self>);
+
+#[test]
+fn test_transparent() {
+    assert_eq!(format!("{:?}", Meters(1.5)), "1.5");
+    assert_eq!(format!("{:6.2?}", Meters(1.5)), "  1.50");
+    assert_eq!(format!("{:?}", Id { value: 7 }), "#7");
+
+    let wrapper = Wrapper {
+        inner: vec![1, 2],
+        cache: None,
+    };
+    assert_eq!(format!("{:?}", wrapper), "[1, 2]");
+    assert_eq!(format!("{:#?}", wrapper), "[\n    1,\n    2,\n]");
+}
+
+#[test]
+fn test_transparent_self() {
+    let node = Node(Some(Box::new(Node(None))));
+    assert_eq!(format!("{:?}", node), "Some(None)");
+}
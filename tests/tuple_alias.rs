@@ -0,0 +1,54 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+struct Color(
+    #[dbg(alias = "r")] u8,
+    #[dbg(alias = "g")] u8,
+    #[dbg(alias = "b")] u8,
+);
+
+#[derive(Dbg)]
+#[allow(dead_code)]
+struct Mixed(
+    u32,
+    #[dbg(alias = "second", fmt = "{:#x}")] u32,
+    #[dbg(skip)] u32,
+    u32,
+);
+
+#[derive(Dbg)]
+struct Plain(u32, u32);
+
+#[derive(Dbg)]
+enum Shape {
+    Point(#[dbg(alias = "x")] i32, #[dbg(alias = "y")] i32),
+    Line(i32, i32),
+}
+
+#[derive(Dbg)]
+#[dbg(logfmt)]
+struct Logged(#[dbg(alias = "id")] u32, u32);
+
+#[test]
+fn test_tuple_alias() {
+    assert_eq!(
+        format!("{:?}", Color(255, 128, 0)),
+        "Color { r: 255, g: 128, b: 0 }"
+    );
+    assert_eq!(
+        format!("{:?}", Mixed(1, 16, 3, 4)),
+        "Mixed { 0: 1, second: 0x10, 3: 4, .. }"
+    );
+    assert_eq!(format!("{:?}", Plain(1, 2)), "Plain(1, 2)");
+    assert_eq!(
+        format!("{:#?}", Color(1, 2, 3)),
+        "Color {\n    r: 1,\n    g: 2,\n    b: 3,\n}"
+    );
+}
+
+#[test]
+fn test_tuple_variant_alias() {
+    assert_eq!(format!("{:?}", Shape::Point(1, 2)), "Point { x: 1, y: 2 }");
+    assert_eq!(format!("{:?}", Shape::Line(1, 2)), "Line(1, 2)");
+    assert_eq!(format!("{:?}", Logged(7, 8)), "Logged id=7 1=8");
+}
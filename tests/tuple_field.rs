@@ -0,0 +1,55 @@
+use derive_debug::Dbg;
+
+struct Meters(pub f32);
+
+#[allow(dead_code)]
+struct Pair(pub u32, pub &'static str);
+
+#[derive(Dbg)]
+struct TestStruct {
+    #[dbg(tuple_field = 0)]
+    distance: Meters,
+    #[dbg(tuple_field = 1)]
+    pair: Pair,
+}
+
+#[derive(Dbg)]
+struct TestTuple(#[dbg(tuple_field = 0)] Meters);
+
+#[derive(Dbg)]
+enum TestEnum {
+    Named {
+        #[dbg(tuple_field = 0)]
+        distance: Meters,
+    },
+    Unnamed(#[dbg(tuple_field = 1)] Pair),
+}
+
+#[test]
+fn test_struct() {
+    let foo = TestStruct {
+        distance: Meters(1.5),
+        pair: Pair(1, "one"),
+    };
+    assert_eq!(
+        format!("{:?}", foo),
+        r#"TestStruct { distance: 1.5, pair: "one" }"#
+    );
+}
+
+#[test]
+fn test_tuple() {
+    let foo = TestTuple(Meters(2.0));
+    assert_eq!(format!("{:?}", foo), "TestTuple(2.0)");
+}
+
+#[test]
+fn test_enum() {
+    let foo = TestEnum::Named {
+        distance: Meters(1.5),
+    };
+    assert_eq!(format!("{:?}", foo), "Named { distance: 1.5 }");
+
+    let foo = TestEnum::Unnamed(Pair(1, "one"));
+    assert_eq!(format!("{:?}", foo), r#"Unnamed("one")"#);
+}
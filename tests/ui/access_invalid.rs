@@ -0,0 +1,6 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+struct Foo(#[dbg(access = "0.len()")] (Vec<u8>,));
+
+fn main() {}
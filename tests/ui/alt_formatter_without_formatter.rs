@@ -0,0 +1,13 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+struct Foo {
+    #[dbg(alt_formatter = "verbose")]
+    timeout: u64,
+}
+
+fn verbose(v: &u64) -> String {
+    format!("{} seconds", v)
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+struct Foo {
+    #[dbg(bytes = "compact")]
+    data: Vec<u8>,
+}
+
+fn main() {}
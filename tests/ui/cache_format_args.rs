@@ -0,0 +1,28 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+struct Foo {
+    a: u32,
+    #[dbg(fmt = "{}/{}", a, b, cache_format)]
+    b: u32,
+}
+
+#[derive(Dbg)]
+struct Bar {
+    #[dbg(fmt = "{:.*}", precision_from = "digits", cache_format)]
+    value: u32,
+    digits: usize,
+}
+
+fn verbosity() -> u8 {
+    1
+}
+
+#[derive(Dbg)]
+#[dbg(verbosity = "verbosity")]
+struct Baz {
+    #[dbg(level = 2, cache_format)]
+    value: u32,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+enum Foo {
+    #[dbg(catch_all)]
+    A(u32, u32),
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+#[dbg(crate_path = "core", detect_cycles)]
+struct Container {
+    a: u32,
+}
+
+#[derive(Dbg)]
+#[dbg(crate_path = "core")]
+struct Field {
+    #[dbg(cache_format)]
+    a: u32,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+struct Foo {
+    #[dbg(deref, formatter = "format_value")]
+    value: Box<u32>,
+}
+
+fn format_value(value: &u32) -> String {
+    value.to_string()
+}
+
+fn main() {}
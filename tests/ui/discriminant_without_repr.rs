@@ -0,0 +1,10 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+#[dbg(discriminant)]
+enum Foo {
+    A,
+    B(u32),
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+struct Foo {
+    #[dbg(fmt = "{:?}", display)]
+    a: u32,
+    #[dbg(display, placeholder = "...")]
+    b: u32,
+    #[dbg(formatter = "u32::to_string", display)]
+    c: u32,
+}
+
+fn main() {}
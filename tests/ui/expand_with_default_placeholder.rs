@@ -0,0 +1,24 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+#[dbg(preset = "Secure")]
+struct Foo {
+    secret: u32,
+    #[dbg(expand_with = "parts")]
+    value: u16,
+}
+
+#[derive(Dbg)]
+#[dbg(preset = "Secure")]
+enum Bar {
+    Variant {
+        #[dbg(expand_with = "parts")]
+        value: u16,
+    },
+}
+
+fn parts(value: &u16) -> [(&'static str, u8); 2] {
+    [("lo", *value as u8), ("hi", (*value >> 8) as u8)]
+}
+
+fn main() {}
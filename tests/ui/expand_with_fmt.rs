@@ -0,0 +1,13 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+struct Foo {
+    #[dbg(expand_with = "parts", fmt = "{:x}")]
+    value: u32,
+}
+
+fn parts(value: &u32) -> [(&'static str, String); 1] {
+    [("value", value.to_string())]
+}
+
+fn main() {}
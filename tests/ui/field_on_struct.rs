@@ -0,0 +1,9 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+#[dbg(field = "a")]
+struct Foo {
+    a: u32,
+}
+
+fn main() {}
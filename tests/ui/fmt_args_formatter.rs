@@ -0,0 +1,14 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+struct Foo {
+    #[dbg(fmt = "{}", self.value)]
+    #[dbg(formatter = "format_value")]
+    value: u32,
+}
+
+fn format_value(value: &u32) -> String {
+    value.to_string()
+}
+
+fn main() {}
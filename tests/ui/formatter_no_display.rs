@@ -0,0 +1,11 @@
+use derive_debug::Dbg;
+
+fn nothing(_: &u32) {}
+
+#[derive(Dbg)]
+struct Foo {
+    #[dbg(formatter = "nothing")]
+    a: u32,
+}
+
+fn main() {}
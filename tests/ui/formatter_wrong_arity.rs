@@ -0,0 +1,13 @@
+use derive_debug::Dbg;
+
+fn two_args(a: &u32, b: u32) -> u32 {
+    a + b
+}
+
+#[derive(Dbg)]
+struct Foo {
+    #[dbg(formatter = "two_args")]
+    a: u32,
+}
+
+fn main() {}
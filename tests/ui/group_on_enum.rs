@@ -0,0 +1,9 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+#[dbg(group("outer", ["a"]))]
+enum Foo {
+    A { a: u32 },
+}
+
+fn main() {}
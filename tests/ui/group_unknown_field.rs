@@ -0,0 +1,10 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+#[dbg(group("outer", [group("inner", ["a", "c"]), "b"]))]
+struct Foo {
+    a: u32,
+    b: u32,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+struct Foo {
+    #[dbg = "skip"]
+    a: u32,
+}
+
+fn main() {}
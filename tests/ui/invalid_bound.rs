@@ -0,0 +1,7 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+#[dbg(bound = "T Debug")]
+struct Foo<T>(T);
+
+fn main() {}
@@ -0,0 +1,7 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+#[dbg(crate_path = "alloc")]
+struct Foo(u32);
+
+fn main() {}
@@ -0,0 +1,9 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+struct Foo {
+    #[dbg(formatter = "not a path")]
+    a: u32,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+#[dbg(rename_all = "lowercase")]
+struct Foo {
+    field_a: u32,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+#[dbg(variant_style = "long")]
+enum Foo {
+    A,
+}
+
+fn main() {}
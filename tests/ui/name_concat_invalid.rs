@@ -0,0 +1,29 @@
+use derive_debug::Dbg;
+
+const NAME: &str = "Name";
+
+#[derive(Dbg)]
+#[dbg(name_concat = "NAME, b\"bytes\"")]
+struct Bytes;
+
+#[derive(Dbg)]
+#[dbg(name_concat = "NAME", alias = "Alias")]
+struct WithAlias;
+
+#[derive(Dbg)]
+#[dbg(name_concat = "NAME")]
+enum Enum {
+    A,
+}
+
+#[derive(Dbg)]
+#[dbg(name_concat = "NAME, 1u8")]
+struct NotStr;
+
+const NUMBER: u32 = 1;
+
+#[derive(Dbg)]
+#[dbg(name_concat = "NUMBER")]
+struct NotStrConst;
+
+fn main() {}
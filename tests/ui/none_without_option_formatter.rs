@@ -0,0 +1,9 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+struct Foo {
+    #[dbg(none = "-")]
+    a: Option<u32>,
+}
+
+fn main() {}
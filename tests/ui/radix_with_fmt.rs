@@ -0,0 +1,11 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+struct Foo {
+    #[dbg(fmt = "{}", hex)]
+    a: u32,
+    #[dbg(oct, placeholder = "...")]
+    b: u32,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+#[dbg(rename_all = "snake_case", rename_all_with = "to_upper")]
+struct Foo {
+    field_a: u32,
+}
+
+fn to_upper(name: &str) -> String {
+    name.to_uppercase()
+}
+
+fn main() {}
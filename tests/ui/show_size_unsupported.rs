@@ -0,0 +1,13 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+#[dbg(show_size)]
+struct Tuple(u32);
+
+#[derive(Dbg)]
+#[dbg(show_size = "size")]
+enum Enum {
+    A,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+#[dbg(skip_bound(U))]
+struct Foo<T> {
+    value: T,
+}
+
+fn main() {}
@@ -0,0 +1,20 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+#[dbg(transparent)]
+struct Foo {
+    a: u32,
+    b: u32,
+}
+
+#[derive(Dbg)]
+#[dbg(transparent)]
+struct Bar(#[dbg(skip)] u32);
+
+#[derive(Dbg)]
+#[dbg(transparent)]
+enum Baz {
+    A(u32),
+}
+
+fn main() {}
@@ -0,0 +1,21 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+#[dbg(transparent)]
+struct Boxed(Box<Self>);
+
+#[derive(Dbg)]
+#[dbg(transparent)]
+struct Named {
+    inner: Box<Named>,
+}
+
+#[derive(Dbg)]
+#[dbg(transparent)]
+struct Skipped {
+    #[dbg(skip)]
+    other: Box<Self>,
+    value: u32,
+}
+
+fn main() {}
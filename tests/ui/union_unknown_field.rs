@@ -0,0 +1,10 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+#[dbg(field = "c")]
+union Foo {
+    a: u32,
+    b: f32,
+}
+
+fn main() {}
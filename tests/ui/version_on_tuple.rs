@@ -0,0 +1,13 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+#[dbg(version = "v1")]
+struct Foo(u32);
+
+#[derive(Dbg)]
+#[dbg(version_field = "schema")]
+struct Bar {
+    a: u32,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+struct Foo {
+    #[dbg(with_args(1))]
+    a: u32,
+}
+
+fn main() {}
@@ -0,0 +1,33 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+#[allow(dead_code)]
+union Opaque {
+    a: u32,
+    b: f32,
+}
+
+#[derive(Dbg)]
+#[dbg(alias = "Renamed")]
+#[allow(dead_code)]
+union Aliased {
+    a: u32,
+}
+
+#[derive(Dbg)]
+#[dbg(field = "bits")]
+union Bits {
+    #[dbg(fmt = "{:#x}", alias = "raw")]
+    bits: u32,
+    float: f32,
+}
+
+#[test]
+fn test_union() {
+    assert_eq!(format!("{:?}", Opaque { a: 1 }), "Opaque { .. }");
+    assert_eq!(format!("{:?}", Aliased { a: 1 }), "Renamed { .. }");
+    assert_eq!(
+        format!("{:?}", Bits { float: 1.0 }),
+        "Bits { raw: 0x3f800000, .. }"
+    );
+}
@@ -0,0 +1,30 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+struct TestStruct {
+    #[dbg(unit = "ms")]
+    duration: u32,
+    #[dbg(fmt = "{:.1}", unit = " km")]
+    distance: f32,
+    #[dbg(fmt = "{:#x}", unit = "h")]
+    addr: u32,
+}
+
+#[derive(Dbg)]
+enum TestEnum {
+    Variant(#[dbg(unit = "°C")] i32),
+}
+
+#[test]
+fn test_unit() {
+    let s = TestStruct {
+        duration: 1500,
+        distance: 2.54,
+        addr: 255,
+    };
+    assert_eq!(
+        format!("{:?}", s),
+        "TestStruct { duration: 1500ms, distance: 2.5 km, addr: 0xffh }"
+    );
+    assert_eq!(format!("{:?}", TestEnum::Variant(-3)), "Variant(-3°C)");
+}
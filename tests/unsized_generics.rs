@@ -0,0 +1,32 @@
+use std::fmt::Debug;
+
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+struct Wrapper<T: ?Sized + Debug> {
+    inner: Box<T>,
+}
+
+#[derive(Dbg)]
+struct WhereWrapper<T>(Box<T>)
+where
+    T: ?Sized + Debug;
+
+#[test]
+fn test_str() {
+    let foo: Wrapper<str> = Wrapper {
+        inner: Box::from("abc"),
+    };
+    assert_eq!(format!("{:?}", foo), r#"Wrapper { inner: "abc" }"#);
+
+    let foo: WhereWrapper<str> = WhereWrapper(Box::from("abc"));
+    assert_eq!(format!("{:?}", foo), r#"WhereWrapper("abc")"#);
+}
+
+#[test]
+fn test_trait_object() {
+    let foo: Wrapper<dyn Debug> = Wrapper {
+        inner: Box::new(42u32),
+    };
+    assert_eq!(format!("{:?}", foo), "Wrapper { inner: 42 }");
+}
@@ -0,0 +1,44 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+enum TestEnum {
+    #[dbg(skip_if = "TestEnum::is_empty")]
+    Tuple(Vec<u32>),
+    #[dbg(skip_if = "is_zero")]
+    Named { a: u32, b: u32 },
+    #[dbg(skip_if = "is_zero")]
+    Unit,
+}
+
+impl TestEnum {
+    fn is_empty(&self) -> bool {
+        matches!(self, TestEnum::Tuple(data) if data.is_empty())
+    }
+}
+
+fn is_zero(v: &TestEnum) -> bool {
+    matches!(v, TestEnum::Named { a: 0, b: 0 })
+}
+
+#[test]
+fn test_tuple_variant() {
+    assert_eq!(format!("{:?}", TestEnum::Tuple(vec![])), "Tuple");
+    assert_eq!(
+        format!("{:?}", TestEnum::Tuple(vec![1, 2])),
+        "Tuple([1, 2])"
+    );
+}
+
+#[test]
+fn test_named_variant() {
+    assert_eq!(format!("{:?}", TestEnum::Named { a: 0, b: 0 }), "Named");
+    assert_eq!(
+        format!("{:?}", TestEnum::Named { a: 1, b: 0 }),
+        "Named { a: 1, b: 0 }"
+    );
+}
+
+#[test]
+fn test_unit_variant() {
+    assert_eq!(format!("{:?}", TestEnum::Unit), "Unit");
+}
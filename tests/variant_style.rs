@@ -0,0 +1,67 @@
+#![allow(clippy::enum_variant_names)]
+
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+#[dbg(variant_style = "qualified")]
+enum QualifiedEnum {
+    UnitVariant,
+    TupleVariant(u32, u32),
+    StructVariant {
+        a: u32,
+        b: u32,
+    },
+    #[dbg(alias = "AliasVariant")]
+    AliasedVariant,
+}
+
+#[derive(Dbg)]
+#[dbg(variant_style = "qualified", alias = "Aliased")]
+enum AliasedEnum {
+    UnitVariant,
+}
+
+#[derive(Dbg)]
+#[dbg(variant_style = "short")]
+enum ShortEnum {
+    UnitVariant,
+    TupleVariant(u32, u32),
+    StructVariant { a: u32, b: u32 },
+}
+
+#[test]
+fn test_qualified() {
+    assert_eq!(
+        format!("{:?}", QualifiedEnum::UnitVariant),
+        "QualifiedEnum::UnitVariant"
+    );
+    assert_eq!(
+        format!("{:?}", QualifiedEnum::TupleVariant(0, 1)),
+        "QualifiedEnum::TupleVariant(0, 1)"
+    );
+    assert_eq!(
+        format!("{:?}", QualifiedEnum::StructVariant { a: 0, b: 1 }),
+        "QualifiedEnum::StructVariant { a: 0, b: 1 }"
+    );
+    assert_eq!(
+        format!("{:?}", QualifiedEnum::AliasedVariant),
+        "QualifiedEnum::AliasVariant"
+    );
+    assert_eq!(
+        format!("{:?}", AliasedEnum::UnitVariant),
+        "Aliased::UnitVariant"
+    );
+}
+
+#[test]
+fn test_short() {
+    assert_eq!(format!("{:?}", ShortEnum::UnitVariant), "UnitVariant");
+    assert_eq!(
+        format!("{:?}", ShortEnum::TupleVariant(0, 1)),
+        "TupleVariant(0, 1)"
+    );
+    assert_eq!(
+        format!("{:?}", ShortEnum::StructVariant { a: 0, b: 1 }),
+        "StructVariant { a: 0, b: 1 }"
+    );
+}
@@ -0,0 +1,62 @@
+use std::cell::Cell;
+
+use derive_debug::Dbg;
+
+thread_local! {
+    static LEVEL: Cell<u8> = const { Cell::new(0) };
+}
+
+fn current_level() -> u8 {
+    LEVEL.with(Cell::get)
+}
+
+#[derive(Dbg)]
+#[dbg(verbosity = "current_level")]
+struct Request {
+    path: &'static str,
+    #[dbg(level = 1)]
+    method: &'static str,
+    #[dbg(level = 2, skip_if = "Vec::is_empty")]
+    headers: Vec<&'static str>,
+}
+
+#[derive(Dbg)]
+#[dbg(verbosity = "current_level")]
+enum Event {
+    Tick(u32, #[dbg(level = 2)] u64),
+}
+
+#[test]
+fn test_verbosity() {
+    let request = Request {
+        path: "/",
+        method: "GET",
+        headers: vec!["Accept"],
+    };
+
+    LEVEL.with(|level| level.set(0));
+    assert_eq!(format!("{:?}", request), "Request { path: \"/\" }");
+    assert_eq!(format!("{:?}", Event::Tick(1, 2)), "Tick(1)");
+
+    LEVEL.with(|level| level.set(1));
+    assert_eq!(
+        format!("{:?}", request),
+        "Request { path: \"/\", method: \"GET\" }"
+    );
+
+    LEVEL.with(|level| level.set(2));
+    assert_eq!(
+        format!("{:?}", request),
+        "Request { path: \"/\", method: \"GET\", headers: [\"Accept\"] }"
+    );
+    assert_eq!(format!("{:?}", Event::Tick(1, 2)), "Tick(1, 2)");
+
+    let request = Request {
+        headers: vec![],
+        ..request
+    };
+    assert_eq!(
+        format!("{:?}", request),
+        "Request { path: \"/\", method: \"GET\" }"
+    );
+}
@@ -0,0 +1,41 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+#[dbg(version = "v3")]
+struct Config {
+    name: &'static str,
+    #[dbg(skip_if = "Option::is_none")]
+    port: Option<u16>,
+}
+
+#[derive(Dbg)]
+#[dbg(version = "2.1", version_field = "schema_version")]
+struct Renamed {
+    value: u32,
+}
+
+#[derive(Dbg)]
+#[dbg(version = "v1")]
+struct Empty;
+
+#[test]
+fn test_version() {
+    let config = Config {
+        name: "app",
+        port: None,
+    };
+    assert_eq!(
+        format!("{:?}", config),
+        "Config { _schema: \"v3\", name: \"app\" }"
+    );
+    assert_eq!(
+        format!("{:#?}", config),
+        "Config {\n    _schema: \"v3\",\n    name: \"app\",\n}"
+    );
+
+    assert_eq!(
+        format!("{:?}", Renamed { value: 1 }),
+        "Renamed { schema_version: \"2.1\", value: 1 }"
+    );
+    assert_eq!(format!("{:?}", Empty), "Empty { _schema: \"v1\" }");
+}
@@ -0,0 +1,46 @@
+use derive_debug::Dbg;
+use std::fmt::{Formatter, Result};
+
+trait DebugFormat<T> {
+    fn fmt(value: &T, f: &mut Formatter) -> Result;
+}
+
+struct HexView;
+
+impl DebugFormat<u32> for HexView {
+    fn fmt(value: &u32, f: &mut Formatter) -> Result {
+        write!(f, "{:#x}", value)
+    }
+}
+
+impl DebugFormat<Vec<u8>> for HexView {
+    fn fmt(value: &Vec<u8>, f: &mut Formatter) -> Result {
+        for byte in value {
+            write!(f, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Dbg)]
+struct TestStruct {
+    #[dbg(via = "HexView")]
+    a: u32,
+    #[dbg(via = "HexView")]
+    b: Vec<u8>,
+}
+
+#[derive(Dbg)]
+enum TestEnum {
+    Variant(#[dbg(via = "HexView")] u32),
+}
+
+#[test]
+fn test_via() {
+    let s = TestStruct {
+        a: 255,
+        b: vec![0xde, 0xad],
+    };
+    assert_eq!(format!("{:?}", s), "TestStruct { a: 0xff, b: dead }");
+    assert_eq!(format!("{:?}", TestEnum::Variant(16)), "Variant(0x10)");
+}
@@ -0,0 +1,89 @@
+use std::fmt;
+
+use derive_debug::Dbg;
+
+fn fmt_float(v: &f64, precision: usize, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{:.*}", precision, v)
+}
+
+fn fmt_unit(v: &u32, unit: &str, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{}{}", v, unit)
+}
+
+fn fmt_hex(v: &u32, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{:#x}", v)
+}
+
+#[derive(Dbg)]
+struct TestStruct {
+    #[dbg(with = "fmt_float", with_args(precision = 3))]
+    a: f64,
+    #[dbg(with = "fmt_float", with_args(1))]
+    b: f64,
+    #[dbg(with_args(unit = "ms"), with = "fmt_unit")]
+    c: u32,
+    #[dbg(with = "fmt_hex")]
+    d: u32,
+}
+
+#[derive(Dbg)]
+enum TestEnum {
+    Tuple(#[dbg(with = "fmt_hex")] u32),
+    Named {
+        #[dbg(with = "fmt_float", with_args(precision = 2))]
+        a: f64,
+    },
+}
+
+#[test]
+fn test_struct() {
+    let foo = TestStruct {
+        a: std::f64::consts::PI,
+        b: 2.25,
+        c: 1500,
+        d: 255,
+    };
+    assert_eq!(
+        format!("{:?}", foo),
+        "TestStruct { a: 3.142, b: 2.2, c: 1500ms, d: 0xff }"
+    );
+}
+
+#[test]
+fn test_enum() {
+    assert_eq!(format!("{:?}", TestEnum::Tuple(255)), "Tuple(0xff)");
+    assert_eq!(
+        format!("{:?}", TestEnum::Named { a: 0.125 }),
+        "Named { a: 0.12 }"
+    );
+}
+
+fn fmt_padded(v: &u32, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{}:{:?}:{:?}:{}", v, f.width(), f.precision(), f.fill())
+}
+
+fn fmt_aligned(v: &str, f: &mut fmt::Formatter) -> fmt::Result {
+    f.pad(v)
+}
+
+#[derive(Dbg)]
+struct Padded {
+    #[dbg(with = "fmt_padded")]
+    a: u32,
+    #[dbg(with = "fmt_aligned")]
+    b: &'static str,
+}
+
+#[test]
+fn test_parent_formatter() {
+    let padded = Padded { a: 1, b: "x" };
+    assert_eq!(format!("{:?}", padded), "Padded { a: 1:None:None: , b: x }");
+    assert_eq!(
+        format!("{:*>4.2?}", padded),
+        "Padded { a: 1:Some(4):Some(2):*, b: ***x }"
+    );
+    assert_eq!(
+        format!("{:<#3?}", padded).lines().nth(2),
+        Some("    b: x  ,")
+    );
+}
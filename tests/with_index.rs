@@ -0,0 +1,46 @@
+use derive_debug::Dbg;
+
+#[derive(Dbg)]
+#[dbg(with_index)]
+struct Plain(u32, bool);
+
+#[derive(Dbg)]
+#[dbg(with_index, rename_all = "SCREAMING_SNAKE_CASE")]
+struct Screaming(u32, #[dbg(alias = "flag")] bool);
+
+#[derive(Dbg)]
+#[dbg(with_index, rename_all = "PascalCase")]
+enum Shape {
+    Point(i32, i32),
+    Unit,
+}
+
+#[derive(Dbg)]
+#[dbg(rename_all = "SCREAMING_SNAKE_CASE")]
+struct Aliased(#[dbg(alias = "x")] u32, u32);
+
+#[test]
+fn test_with_index() {
+    assert_eq!(
+        format!("{:?}", Plain(1, true)),
+        "Plain { field0: 1, field1: true }"
+    );
+}
+
+#[test]
+fn test_with_index_rename_all() {
+    assert_eq!(
+        format!("{:?}", Screaming(1, true)),
+        "Screaming { FIELD0: 1, flag: true }"
+    );
+    assert_eq!(
+        format!("{:?}", Shape::Point(1, 2)),
+        "Point { Field0: 1, Field1: 2 }"
+    );
+    assert_eq!(format!("{:?}", Shape::Unit), "Unit");
+}
+
+#[test]
+fn test_alias_rename_all() {
+    assert_eq!(format!("{:?}", Aliased(1, 2)), "Aliased { x: 1, 1: 2 }");
+}